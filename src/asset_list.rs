@@ -0,0 +1,47 @@
+//! The `assets` option of a plugin, which may hold a single asset, a path or
+//! an object, in place of an array.
+
+use serde_json::Value;
+
+/// Whether `assets` is a single asset given in place of an array.
+pub(crate) fn is_single(assets: &Value) -> bool {
+    assets.is_string() || assets.is_object()
+}
+
+/// The assets listed in `assets`.
+pub(crate) fn items(assets: &Value) -> &[Value] {
+    match assets {
+        Value::Array(assets) => assets,
+        asset if is_single(asset) => std::slice::from_ref(asset),
+        _ => &[],
+    }
+}
+
+/// The assets listed in `assets`, and whether a single asset was given in
+/// place of an array. Any other shape is returned as the error.
+pub(crate) fn into_items(assets: Value) -> Result<(Vec<Value>, bool), Value> {
+    match assets {
+        Value::Array(assets) => Ok((assets, false)),
+        asset if is_single(&asset) => Ok((vec![asset], true)),
+        assets => Err(assets),
+    }
+}
+
+/// The assets listed in `assets`, first wrapping a single asset in an array
+/// so more can be added.
+pub(crate) fn items_mut(assets: &mut Value) -> Option<&mut Vec<Value>> {
+    if is_single(assets) {
+        *assets = Value::Array(vec![assets.take()]);
+    }
+    assets.as_array_mut()
+}
+
+/// Keep only the assets for which `keep` returns true. A single asset is left
+/// as it is unless removed, which leaves an empty array.
+pub(crate) fn retain(assets: &mut Value, mut keep: impl FnMut(&Value) -> bool) {
+    match assets {
+        Value::Array(assets) => assets.retain(keep),
+        asset if is_single(asset) && !keep(asset) => *asset = Value::Array(Vec::new()),
+        _ => {}
+    }
+}
@@ -0,0 +1,123 @@
+//! Plugins whose `assets` option this crate can edit.
+
+use crate::{error::Error, AssetField};
+
+/// A semantic-release plugin configured with an `assets` option.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AssetPlugin {
+    /// @semantic-release/github, whose assets are paths or objects with a
+    /// `path`, `label` and `name`
+    #[default]
+    Github,
+    /// @semantic-release/git, whose assets are paths of files to commit
+    Git,
+    /// @semantic-release/gitlab, whose assets are paths or objects with a
+    /// `path` and `label`, alongside properties such as `type` and `filepath`
+    Gitlab,
+    /// @saithodev/semantic-release-gitea, whose assets are paths or objects
+    /// with a `path` and `name`
+    Gitea,
+}
+
+/// The shape of the assets of an [`AssetPlugin`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AssetSchema {
+    /// Properties this crate edits besides `path`; any others are preserved
+    pub properties: &'static [AssetField],
+    /// Whether a new asset is written as a bare path rather than an object
+    pub bare_path: bool,
+}
+
+impl AssetPlugin {
+    /// Every plugin whose assets can be edited.
+    pub const ALL: &'static [AssetPlugin] = &[
+        AssetPlugin::Github,
+        AssetPlugin::Git,
+        AssetPlugin::Gitlab,
+        AssetPlugin::Gitea,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AssetPlugin::Github => "@semantic-release/github",
+            AssetPlugin::Git => "@semantic-release/git",
+            AssetPlugin::Gitlab => "@semantic-release/gitlab",
+            AssetPlugin::Gitea => "@saithodev/semantic-release-gitea",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|plugin| plugin.name() == name)
+    }
+
+    pub fn schema(self) -> AssetSchema {
+        match self {
+            AssetPlugin::Github => AssetSchema {
+                properties: &[AssetField::Label, AssetField::Name],
+                bare_path: false,
+            },
+            AssetPlugin::Git => AssetSchema {
+                properties: &[],
+                bare_path: true,
+            },
+            AssetPlugin::Gitlab => AssetSchema {
+                properties: &[AssetField::Label],
+                bare_path: false,
+            },
+            AssetPlugin::Gitea => AssetSchema {
+                properties: &[AssetField::Name],
+                bare_path: false,
+            },
+        }
+    }
+
+    /// The asset property whitelists and blacklists match by default: the
+    /// label where assets have one, otherwise the path.
+    pub fn default_field(self) -> AssetField {
+        match self.supports(AssetField::Label) {
+            true => AssetField::Label,
+            false => AssetField::Path,
+        }
+    }
+
+    /// Whether assets of this plugin have a `field` property.
+    pub fn supports(self, field: AssetField) -> bool {
+        match field {
+            AssetField::Path | AssetField::Any => true,
+            field => self.schema().properties.contains(&field),
+        }
+    }
+
+    /// A new asset in the shape this plugin expects.
+    pub(crate) fn new_asset(
+        self,
+        path: &str,
+        label: Option<&str>,
+        name: Option<&str>,
+    ) -> Result<serde_json::Value, Error> {
+        let properties = [
+            (AssetField::Label, "label", label),
+            (AssetField::Name, "name", name),
+        ];
+        for (field, key, value) in properties {
+            if value.is_some() && !self.supports(field) {
+                return Err(Error::unsupported_asset_field_error(self.name(), key));
+            }
+        }
+
+        if self.schema().bare_path {
+            return Ok(path.into());
+        }
+        let mut asset = serde_json::Map::new();
+        asset.insert("path".to_owned(), path.into());
+        for (_, key, value) in properties {
+            if let Some(value) = value {
+                asset.insert(key.to_owned(), value.into());
+            }
+        }
+        Ok(serde_json::Value::Object(asset))
+    }
+}
@@ -0,0 +1,180 @@
+//! Discover the binaries built by a Cargo package or workspace.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use toml::{Table, Value};
+
+use crate::error::Error;
+
+const CARGO_MANIFEST: &str = "Cargo.toml";
+
+/// Names of the binaries built by the package or workspace whose
+/// `Cargo.toml` is in `directory`.
+///
+/// A package builds its `[[bin]]` targets and, unless `autobins` is false,
+/// the binaries Cargo discovers in `src/main.rs` and `src/bin`; a library-only
+/// package builds none. A workspace builds the binaries of each of its
+/// members, in the order the members are listed.
+pub fn find_cargo_binaries(directory: &Path) -> Result<Vec<String>, Error> {
+    let path = directory.join(CARGO_MANIFEST);
+    let manifest = read_cargo_manifest(&path)?;
+
+    let mut binaries = Vec::new();
+    if let Some(package) = manifest.get("package") {
+        binaries.extend(package_binaries(directory, &manifest, package, &path)?);
+    }
+    if let Some(workspace) = manifest.get("workspace") {
+        for member in workspace_members(directory, workspace, &path)? {
+            let path = member.join(CARGO_MANIFEST);
+            let manifest = read_cargo_manifest(&path)?;
+            let package = manifest
+                .get("package")
+                .ok_or_else(|| Error::cargo_manifest_shape_error("package", &path))?;
+            binaries.extend(package_binaries(&member, &manifest, package, &path)?);
+        }
+    }
+    if manifest.get("package").is_none() && manifest.get("workspace").is_none() {
+        return Err(Error::cargo_manifest_shape_error("package", &path));
+    }
+
+    let mut unique = Vec::with_capacity(binaries.len());
+    for binary in binaries {
+        if !unique.contains(&binary) {
+            unique.push(binary);
+        }
+    }
+    Ok(unique)
+}
+
+fn read_cargo_manifest(path: &Path) -> Result<Table, Error> {
+    let contents = fs::read_to_string(path).map_err(|err| Error::file_read_error(err, path))?;
+    contents
+        .parse::<Table>()
+        .map_err(|err| Error::cargo_manifest_parse_error(err, path))
+}
+
+fn package_binaries(
+    directory: &Path,
+    manifest: &Table,
+    package: &Value,
+    path: &Path,
+) -> Result<Vec<String>, Error> {
+    let mut binaries = Vec::new();
+    let mut declared_paths = Vec::new();
+    match manifest.get("bin") {
+        Some(Value::Array(bins)) => {
+            for bin in bins {
+                let name = bin
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| Error::cargo_manifest_shape_error("bin.name", path))?;
+                binaries.push(name.to_owned());
+                if let Some(bin_path) = bin.get("path").and_then(Value::as_str) {
+                    declared_paths.push(directory.join(bin_path));
+                }
+            }
+        }
+        Some(_) => return Err(Error::cargo_manifest_shape_error("bin", path)),
+        None => {}
+    }
+
+    let autobins = package
+        .get("autobins")
+        .and_then(Value::as_bool)
+        .unwrap_or(true);
+    if autobins {
+        let name = package
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::cargo_manifest_shape_error("package.name", path))?;
+        binaries.extend(
+            discover_binaries(directory, name)
+                .into_iter()
+                .filter(|(_, bin_path)| !declared_paths.contains(bin_path))
+                .map(|(name, _)| name),
+        );
+    }
+    Ok(binaries)
+}
+
+/// Binaries Cargo infers from the layout of a package: `src/main.rs`, named
+/// after the package, then `src/bin/*.rs` and `src/bin/*/main.rs`.
+fn discover_binaries(directory: &Path, package_name: &str) -> Vec<(String, PathBuf)> {
+    let mut binaries = Vec::new();
+    let main = directory.join("src").join("main.rs");
+    if main.is_file() {
+        binaries.push((package_name.to_owned(), main));
+    }
+
+    let Ok(entries) = fs::read_dir(directory.join("src").join("bin")) else {
+        return binaries;
+    };
+    let mut discovered: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter_map(|path| {
+            let bin_path = match path.is_dir() {
+                true => path.join("main.rs"),
+                false if path.extension().is_some_and(|extension| extension == "rs") => {
+                    path.clone()
+                }
+                false => return None,
+            };
+            let name = path.file_stem()?.to_str()?.to_owned();
+            bin_path.is_file().then_some((name, bin_path))
+        })
+        .collect();
+    discovered.sort();
+    binaries.extend(discovered);
+    binaries
+}
+
+fn workspace_members(
+    directory: &Path,
+    workspace: &Value,
+    path: &Path,
+) -> Result<Vec<PathBuf>, Error> {
+    let strings = |key: &str| -> Result<Vec<&str>, Error> {
+        match workspace.get(key) {
+            Some(Value::Array(values)) => values
+                .iter()
+                .map(|value| {
+                    value.as_str().ok_or_else(|| {
+                        Error::cargo_manifest_shape_error(&format!("workspace.{}", key), path)
+                    })
+                })
+                .collect(),
+            Some(_) => Err(Error::cargo_manifest_shape_error(
+                &format!("workspace.{}", key),
+                path,
+            )),
+            None => Ok(Vec::new()),
+        }
+    };
+
+    let excluded: Vec<PathBuf> = strings("exclude")?
+        .into_iter()
+        .map(|exclude| directory.join(exclude))
+        .collect();
+
+    let mut members = Vec::new();
+    for member in strings("members")? {
+        let pattern = directory.join(member);
+        let pattern = pattern.to_string_lossy();
+        let mut matches: Vec<PathBuf> = glob::glob(&pattern)
+            .map_err(|_| Error::cargo_manifest_shape_error("workspace.members", path))?
+            .flatten()
+            .filter(|member| member.join(CARGO_MANIFEST).is_file())
+            .collect();
+        matches.sort();
+        members.extend(
+            matches
+                .into_iter()
+                .filter(|member| !excluded.contains(member)),
+        );
+    }
+    Ok(members)
+}
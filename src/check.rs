@@ -0,0 +1,94 @@
+use std::{
+    io::{self, Write},
+    path::Path,
+};
+
+use configure_semantic_release_assets::{AssetPlugin, ModifiedFlag, SemanticReleaseManifest};
+
+/// Exit status of `--check` when the manifest would be modified, distinct
+/// from the status of errors (1) and usage errors (2).
+pub(crate) const MODIFIED_EXIT_CODE: i32 = 3;
+
+/// One line per asset, naming its label, path and file name, so an edit of
+/// any of them shows in the summary.
+pub(crate) fn describe_assets(
+    manifest: &SemanticReleaseManifest,
+    plugin: AssetPlugin,
+) -> Vec<String> {
+    manifest
+        .assets_in(plugin)
+        .iter()
+        .map(|asset| {
+            let mut line = match asset.label {
+                Some(label) => format!("{} ({})", label, asset.path.join(" ")),
+                None => asset.path.join(" "),
+            };
+            if let Some(name) = asset.name {
+                line.push_str(&format!(" as {}", name));
+            }
+            line
+        })
+        .collect()
+}
+
+/// One line per plugin, in order, naming its options other than the assets
+/// already described by [`describe_assets`].
+pub(crate) fn describe_plugins(
+    manifest: &SemanticReleaseManifest,
+    plugin: AssetPlugin,
+) -> Vec<String> {
+    manifest
+        .plugins()
+        .iter()
+        .map(|candidate| {
+            let mut options = candidate.configuration.cloned().unwrap_or_default();
+            if candidate.name == plugin.name() {
+                options.retain(|key, _| key != "assets");
+            }
+            match options.is_empty() {
+                true => format!("plugin {}", candidate.name),
+                false => format!(
+                    "plugin {} {}",
+                    candidate.name,
+                    serde_json::Value::Object(options)
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Every line the summary compares: the assets of `plugin`, then the plugins.
+pub(crate) fn describe(manifest: &SemanticReleaseManifest, plugin: AssetPlugin) -> Vec<String> {
+    let mut lines = describe_assets(manifest, plugin);
+    lines.extend(describe_plugins(manifest, plugin));
+    lines
+}
+
+pub(crate) fn print_summary(
+    mut w: impl Write,
+    path: &Path,
+    original: &[String],
+    edited: &[String],
+    modified: &ModifiedFlag,
+) -> io::Result<()> {
+    match modified {
+        ModifiedFlag::Unmodified => {
+            writeln!(w, "{} is up to date", path.display())?;
+        }
+        ModifiedFlag::Modified => {
+            writeln!(w, "{} would be modified", path.display())?;
+            // Compare by position, so repeated and reordered lines show
+            for op in similar::capture_diff_slices(similar::Algorithm::Myers, original, edited) {
+                for change in op.iter_changes(original, edited) {
+                    match change.tag() {
+                        similar::ChangeTag::Delete => writeln!(w, "- {}", change.value())?,
+                        similar::ChangeTag::Insert => writeln!(w, "+ {}", change.value())?,
+                        similar::ChangeTag::Equal => {}
+                    }
+                }
+            }
+        }
+    }
+
+    w.flush()
+}
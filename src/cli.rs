@@ -1,6 +1,9 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use configure_semantic_release_assets::{
+    AssetField, AssetPlugin, ManifestFormat, DEFAULT_PATH_TEMPLATE,
+};
 
 #[derive(Debug, Parser)]
 #[command(author, version, about)]
@@ -9,10 +12,58 @@ pub(crate) struct Cli {
     #[arg(long, default_value = ".")]
     pub directory: PathBuf,
 
-    /// Edit file in-place
-    #[arg(long, action)]
+    /// Read the manifest from PATH instead of searching DIRECTORY, or from
+    /// stdin when PATH is `-`
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    /// Format of a manifest read from stdin
+    #[arg(long, value_enum, required_if_eq("input", "-"))]
+    pub format: Option<InputFormat>,
+
+    /// Edit file in-place, which is not possible for a manifest read from
+    /// stdin
+    #[arg(long, action, conflicts_with = "format")]
     pub in_place: bool,
 
+    /// Keep the prior manifest, with SUFFIX appended to its file name, when
+    /// editing in-place
+    #[arg(
+        long,
+        value_name = "SUFFIX",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "~",
+        requires = "in_place"
+    )]
+    pub backup: Option<String>,
+
+    /// Write the edited manifest to PATH, leaving the original untouched
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["in_place", "check", "diff"])]
+    pub output: Option<PathBuf>,
+
+    /// Print the resulting manifest to stdout even when nothing changed
+    #[arg(
+        long,
+        visible_alias = "print",
+        action,
+        conflicts_with_all = ["in_place", "output", "check", "diff"]
+    )]
+    pub always_write: bool,
+
+    /// Write nothing; summarize pending changes and exit with status 3 if the
+    /// manifest would be modified
+    #[arg(long, action, conflicts_with = "in_place")]
+    pub check: bool,
+
+    /// Print a unified diff of pending changes instead of the edited manifest
+    #[arg(long, action, conflicts_with_all = ["in_place", "check"])]
+    pub diff: bool,
+
+    /// Plugin whose assets to edit
+    #[arg(long, value_enum, global = true, default_value_t = PluginTarget::Github)]
+    pub plugin: PluginTarget,
+
     #[clap(subcommand)]
     pub subcommand: Subcommand,
 }
@@ -21,8 +72,208 @@ pub(crate) struct Cli {
 pub(crate) enum Subcommand {
     /// Trim release assets to a whitelist
     Whitelist {
-        /// Whitelist of release assets
+        /// Interpret every entry as a regular expression rather than a glob
+        #[arg(long, action)]
+        regex: bool,
+
+        /// Asset property matched against each entry, defaulting to the label
+        /// where assets have one and otherwise the path
+        #[arg(long = "match", value_enum)]
+        match_on: Option<MatchOn>,
+
+        /// Read further whitelist entries from a file: one entry per line,
+        /// a JSON array, or a YAML or JSON document together with `--key`
+        #[arg(long)]
+        from_file: Option<PathBuf>,
+
+        /// Dot-separated key path of the entries in `--from-file`, such as
+        /// `jobs.build.strategy.matrix.target`
+        #[arg(long, requires = "from_file")]
+        key: Option<String>,
+
+        /// Whitelist of release assets, as globs or `regex:`-prefixed regular expressions
         #[arg()]
         whitelist: Vec<String>,
     },
+    /// Remove the named release assets
+    Blacklist {
+        /// Interpret every entry as a regular expression rather than a glob
+        #[arg(long, action)]
+        regex: bool,
+
+        /// Asset property matched against each entry, defaulting to the label
+        /// where assets have one and otherwise the path
+        #[arg(long = "match", value_enum)]
+        match_on: Option<MatchOn>,
+
+        /// Blacklist of release assets, as globs or `regex:`-prefixed regular expressions
+        #[arg()]
+        blacklist: Vec<String>,
+    },
+    /// Add a release asset
+    Add {
+        /// Path of the release asset
+        #[arg(long)]
+        path: String,
+
+        /// Label of the release asset
+        #[arg(long)]
+        label: Option<String>,
+
+        /// File name of the release asset
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Update the asset with the same label instead of refusing
+        #[arg(long, action)]
+        update: bool,
+    },
+    /// Print the configured release assets
+    List {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ListFormat::Text)]
+        format: ListFormat,
+    },
+    /// Replace the assets with one asset per Rust target triple
+    Generate {
+        /// Template of each asset path
+        #[arg(long, default_value = DEFAULT_PATH_TEMPLATE)]
+        path: String,
+
+        /// Template of each asset label, defaulting to `{target}` where
+        /// assets have labels, or `{binary}-{target}` for several binaries
+        #[arg(long)]
+        label: Option<String>,
+
+        /// Template of each asset file name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Binary name substituted for `{binary}`; may be repeated to
+        /// generate assets for several binaries. Defaults to the binaries
+        /// of the Cargo package or workspace in `--directory`
+        #[arg(long)]
+        binary: Vec<String>,
+
+        /// Rust target triples substituted for `{target}`
+        #[arg(required = true)]
+        targets: Vec<String>,
+    },
+    /// Add, remove, reorder or configure plugins
+    Plugin {
+        #[clap(subcommand)]
+        command: PluginCommand,
+    },
+}
+
+#[derive(Debug, Parser)]
+pub(crate) enum PluginCommand {
+    /// Add a plugin, in string form unless options are given
+    Add {
+        /// Name of the plugin
+        name: String,
+
+        /// Position in the `plugins` array, defaulting to the end
+        #[arg(long)]
+        index: Option<usize>,
+
+        /// Options, as `key=value` pairs or JSON objects
+        options: Vec<String>,
+    },
+    /// Remove a plugin
+    Remove {
+        /// Name of the plugin
+        name: String,
+    },
+    /// Move a plugin to another position in the `plugins` array
+    Move {
+        /// Name of the plugin
+        name: String,
+
+        /// New position in the `plugins` array
+        index: usize,
+    },
+    /// Set options of a plugin, keeping its other options
+    Set {
+        /// Name of the plugin
+        name: String,
+
+        /// Options, as `key=value` pairs or JSON objects
+        #[arg(required = true)]
+        options: Vec<String>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(crate) enum ListFormat {
+    /// Aligned columns of label, path and name
+    Text,
+    /// A JSON array of assets
+    Json,
+    /// Tab-separated label, path and name, one asset per line
+    Tsv,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(crate) enum MatchOn {
+    /// Match against the asset `label`
+    Label,
+    /// Match against the asset `path`
+    Path,
+    /// Match against the asset `name`
+    Name,
+    /// Match against any of `label`, `path` or `name`
+    Any,
+}
+
+impl From<MatchOn> for AssetField {
+    fn from(match_on: MatchOn) -> Self {
+        match match_on {
+            MatchOn::Label => AssetField::Label,
+            MatchOn::Path => AssetField::Path,
+            MatchOn::Name => AssetField::Name,
+            MatchOn::Any => AssetField::Any,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(crate) enum InputFormat {
+    Json,
+    Yaml,
+    #[value(name = "js")]
+    JavaScript,
+}
+
+impl From<InputFormat> for ManifestFormat {
+    fn from(format: InputFormat) -> Self {
+        match format {
+            InputFormat::Json => ManifestFormat::Json,
+            InputFormat::Yaml => ManifestFormat::Yaml,
+            InputFormat::JavaScript => ManifestFormat::JavaScript,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(crate) enum PluginTarget {
+    /// Release assets of @semantic-release/github
+    Github,
+    /// Files committed by @semantic-release/git
+    Git,
+    /// Release assets of @semantic-release/gitlab
+    Gitlab,
+    /// Release assets of @saithodev/semantic-release-gitea
+    Gitea,
+}
+
+impl From<PluginTarget> for AssetPlugin {
+    fn from(target: PluginTarget) -> Self {
+        match target {
+            PluginTarget::Github => AssetPlugin::Github,
+            PluginTarget::Git => AssetPlugin::Git,
+            PluginTarget::Gitlab => AssetPlugin::Gitlab,
+            PluginTarget::Gitea => AssetPlugin::Gitea,
+        }
+    }
 }
@@ -0,0 +1,149 @@
+//! Whitelist entries read from a file.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde_yaml::Value;
+
+/// Read whitelist entries from `path`.
+///
+/// With a `key`, the file is parsed as YAML (or JSON) and the entries are the
+/// strings found at the dot-separated key path. A segment applied to a list
+/// is applied to each of its items, so `strategy.matrix.include.target`
+/// collects the `target` of every `include` entry. Items without the segment
+/// are skipped; the key path is missing only when no item has it. Without a
+/// `key`, the file is either a JSON array of strings or one entry per line,
+/// where blank lines and lines starting with `#` are ignored.
+pub(crate) fn read_entries(path: &Path, key: Option<&str>) -> Result<Vec<String>, EntryFileError> {
+    let contents = fs::read_to_string(path).map_err(|source| EntryFileError::Read {
+        source,
+        path: path.to_owned(),
+    })?;
+
+    let (value, key) = match key {
+        Some(key) => {
+            let document: Value =
+                serde_yaml::from_str(&contents).map_err(|source| EntryFileError::Parse {
+                    source,
+                    path: path.to_owned(),
+                })?;
+            let value = key
+                .split('.')
+                .filter(|segment| !segment.is_empty())
+                .try_fold(document, lookup)
+                .ok_or_else(|| EntryFileError::MissingKey {
+                    key: key.to_owned(),
+                    path: path.to_owned(),
+                })?;
+            (value, key)
+        }
+        None if contents.trim_start().starts_with('[') => {
+            let value =
+                serde_yaml::from_str(&contents).map_err(|source| EntryFileError::Parse {
+                    source,
+                    path: path.to_owned(),
+                })?;
+            (value, "")
+        }
+        None => {
+            return Ok(contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_owned)
+                .collect())
+        }
+    };
+
+    let mut entries = Vec::new();
+    collect_strings(value, &mut entries).ok_or_else(|| EntryFileError::NotAList {
+        key: key.to_owned(),
+        path: path.to_owned(),
+    })?;
+    Ok(entries)
+}
+
+fn lookup(value: Value, segment: &str) -> Option<Value> {
+    match value {
+        Value::Sequence(items) => match segment.parse::<usize>() {
+            Ok(index) => items.into_iter().nth(index),
+            Err(_) => {
+                let found: Vec<Value> = items
+                    .into_iter()
+                    .filter_map(|item| lookup(item, segment))
+                    .collect();
+                (!found.is_empty()).then_some(Value::Sequence(found))
+            }
+        },
+        Value::Mapping(mut mapping) => mapping.remove(segment),
+        Value::Tagged(tagged) => lookup(tagged.value, segment),
+        _ => None,
+    }
+}
+
+fn collect_strings(value: Value, entries: &mut Vec<String>) -> Option<()> {
+    match value {
+        Value::String(entry) => entries.push(entry),
+        Value::Sequence(items) => {
+            for item in items {
+                collect_strings(item, entries)?;
+            }
+        }
+        _ => return None,
+    }
+    Some(())
+}
+
+#[derive(Debug)]
+pub(crate) enum EntryFileError {
+    /// Unable to read file {path}
+    Read { source: io::Error, path: PathBuf },
+
+    /// Unable to parse file {path}
+    Parse {
+        source: serde_yaml::Error,
+        path: PathBuf,
+    },
+
+    /// File {path} has no value at key path {key}
+    MissingKey { key: String, path: PathBuf },
+
+    /// Value at key path {key} in file {path} is not a list of strings
+    NotAList { key: String, path: PathBuf },
+}
+
+impl std::error::Error for EntryFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EntryFileError::Read { source, path: _ } => Some(source),
+            EntryFileError::Parse { source, path: _ } => Some(source),
+            EntryFileError::MissingKey { key: _, path: _ } => None,
+            EntryFileError::NotAList { key: _, path: _ } => None,
+        }
+    }
+}
+
+impl std::fmt::Display for EntryFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EntryFileError::Read { source: _, path } => {
+                write!(f, "unable to read file {:?}", path)
+            }
+            EntryFileError::Parse { source: _, path } => {
+                write!(f, "unable to parse file {:?}", path)
+            }
+            EntryFileError::MissingKey { key, path } => {
+                write!(f, "no value at key path {:?} in file {:?}", key, path)
+            }
+            EntryFileError::NotAList { key, path } => {
+                write!(
+                    f,
+                    "expected a list of strings at key path {:?} in file {:?}",
+                    key, path
+                )
+            }
+        }
+    }
+}
@@ -3,10 +3,16 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use crate::literal;
+
+#[allow(clippy::enum_variant_names)]
 pub enum Error {
     /// Expected semantic-release configuration to exist at {path}
     ConfigurationFileNotFound { path: PathBuf },
 
+    /// Expected package.json at {path} to contain a `release` object
+    MissingReleaseProperty { path: PathBuf },
+
     /// Unable to open file {path}
     FileOpenError { source: io::Error, path: PathBuf },
 
@@ -22,19 +28,95 @@ pub enum Error {
     /// Unable to serialize file
     FileSerializeError { source: serde_json::Error },
 
+    /// Unable to parse YAML semantic-release configuration file
+    YamlParseError {
+        source: serde_yaml::Error,
+        path: PathBuf,
+    },
+
+    /// Unable to serialize YAML file
+    YamlSerializeError { source: serde_yaml::Error },
+
+    /// Unable to parse JavaScript semantic-release configuration file
+    JavaScriptParseError {
+        source: literal::ParseError,
+        path: PathBuf,
+    },
+
+    /// JavaScript semantic-release configuration does not export a static object literal
+    DynamicJavaScriptExport { offset: usize, path: PathBuf },
+
+    /// Expected {key} in the semantic-release configuration to have a different type
+    UnexpectedManifestShape { key: String },
+
+    /// Unable to parse the configuration of {plugin}
+    InvalidPluginConfiguration {
+        source: serde_json::Error,
+        plugin: String,
+    },
+
+    /// A plugin named {name} is already configured
+    DuplicatePlugin { name: String },
+
+    /// No plugin named {name} is configured
+    MissingPlugin { name: String },
+
+    /// Assets of {plugin} have no {field} property
+    UnsupportedAssetField { plugin: String, field: String },
+
+    /// An asset with path {path} already exists
+    DuplicateAssetPath { path: String },
+
+    /// Template uses {placeholder} but no value was given for it
+    MissingTemplateValue { placeholder: String },
+
+    /// A release asset labeled {label} already exists
+    DuplicateAssetLabel { label: String },
+
+    /// Unable to parse Cargo manifest {path}
+    CargoManifestParseError {
+        source: toml::de::Error,
+        path: PathBuf,
+    },
+
+    /// Expected {key} in Cargo manifest {path} to exist with a different type
+    UnexpectedCargoManifestShape { key: String, path: PathBuf },
+
     /// Unable to write changes to file {path}
     FileWriteError { source: io::Error, path: PathBuf },
+
+    /// Cannot edit a manifest read from stdin in-place
+    InPlaceStream,
 }
 
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::ConfigurationFileNotFound { path: _ } => None,
+            Error::MissingReleaseProperty { path: _ } => None,
             Error::FileOpenError { source, path: _ } => Some(source),
             Error::FileReadError { source, path: _ } => Some(source),
             Error::FileParseError { source, path: _ } => Some(source),
             Error::FileSerializeError { source } => Some(source),
+            Error::YamlParseError { source, path: _ } => Some(source),
+            Error::YamlSerializeError { source } => Some(source),
+            Error::JavaScriptParseError { source, path: _ } => Some(source),
+            Error::DynamicJavaScriptExport { offset: _, path: _ } => None,
+            Error::UnexpectedManifestShape { key: _ } => None,
+            Error::InvalidPluginConfiguration { source, plugin: _ } => Some(source),
+            Error::DuplicatePlugin { name: _ } => None,
+            Error::MissingPlugin { name: _ } => None,
+            Error::UnsupportedAssetField {
+                plugin: _,
+                field: _,
+            } => None,
+            Error::DuplicateAssetPath { path: _ } => None,
+            Error::MissingTemplateValue { placeholder: _ } => None,
+            Error::DuplicateAssetLabel { label: _ } => None,
+            Error::CargoManifestParseError { source, path: _ } => Some(source),
+            Error::UnexpectedCargoManifestShape { key: _, path: _ } => None,
             Error::FileWriteError { source, path: _ } => Some(source),
+            Error::InPlaceStream => None,
         }
     }
 }
@@ -45,6 +127,13 @@ impl std::fmt::Display for Error {
             Error::ConfigurationFileNotFound { path } => {
                 write!(f, "Expected configuration file does not exist {:?}", path)
             }
+            Error::MissingReleaseProperty { path } => {
+                write!(
+                    f,
+                    "Expected package.json to contain a `release` object {:?}",
+                    path
+                )
+            }
             Error::FileOpenError { source: _, path } => {
                 write!(f, "Unable to open file {:?}", path)
             }
@@ -61,9 +150,82 @@ impl std::fmt::Display for Error {
             Error::FileSerializeError { source: _ } => {
                 write!(f, "Unable to serialize semantic-release configuration")
             }
+            Error::YamlParseError { source: _, path } => {
+                write!(
+                    f,
+                    "Unable to parse YAML semantic-release configuration from {:?}",
+                    path
+                )
+            }
+            Error::YamlSerializeError { source: _ } => {
+                write!(f, "Unable to serialize YAML semantic-release configuration")
+            }
+            Error::JavaScriptParseError { source: _, path } => {
+                write!(
+                    f,
+                    "Unable to parse JavaScript semantic-release configuration from {:?}",
+                    path
+                )
+            }
+            Error::DynamicJavaScriptExport { offset, path } => {
+                write!(
+                    f,
+                    "Unable to edit {:?}: exported configuration is not a static object literal (offset {})",
+                    path, offset
+                )
+            }
+            Error::UnexpectedManifestShape { key } => {
+                write!(
+                    f,
+                    "Unexpected type for {:?} in semantic-release configuration",
+                    key
+                )
+            }
+            Error::InvalidPluginConfiguration { source: _, plugin } => {
+                write!(
+                    f,
+                    "Unable to parse the configuration of plugin {:?}",
+                    plugin
+                )
+            }
+            Error::DuplicatePlugin { name } => {
+                write!(f, "A plugin named {:?} is already configured", name)
+            }
+            Error::MissingPlugin { name } => {
+                write!(f, "No plugin named {:?} is configured", name)
+            }
+            Error::UnsupportedAssetField { plugin, field } => {
+                write!(f, "Assets of {:?} have no {:?} property", plugin, field)
+            }
+            Error::DuplicateAssetPath { path } => {
+                write!(f, "An asset with path {:?} already exists", path)
+            }
+            Error::MissingTemplateValue { placeholder } => {
+                write!(
+                    f,
+                    "Template uses {} but no value was given for it",
+                    placeholder
+                )
+            }
+            Error::DuplicateAssetLabel { label } => {
+                write!(f, "A release asset labeled {:?} already exists", label)
+            }
+            Error::CargoManifestParseError { source: _, path } => {
+                write!(f, "Unable to parse Cargo manifest {:?}", path)
+            }
+            Error::UnexpectedCargoManifestShape { key, path } => {
+                write!(
+                    f,
+                    "Missing or unexpected type for {:?} in Cargo manifest {:?}",
+                    key, path
+                )
+            }
             Error::FileWriteError { source: _, path } => {
                 write!(f, "{:?}", path)
             }
+            Error::InPlaceStream => {
+                write!(f, "Cannot edit a manifest read from stdin in-place")
+            }
         }
     }
 }
@@ -94,6 +256,12 @@ impl Error {
         }
     }
 
+    pub(crate) fn missing_release_property_error(path: &Path) -> Error {
+        Error::MissingReleaseProperty {
+            path: path.to_owned(),
+        }
+    }
+
     pub(crate) fn file_open_error(source: io::Error, path: &Path) -> Error {
         Error::FileOpenError {
             source,
@@ -119,10 +287,105 @@ impl Error {
         Error::FileSerializeError { source }
     }
 
+    pub(crate) fn yaml_parse_error(source: serde_yaml::Error, path: &Path) -> Error {
+        Error::YamlParseError {
+            source,
+            path: path.to_owned(),
+        }
+    }
+
+    pub(crate) fn yaml_serialize_error(source: serde_yaml::Error) -> Error {
+        Error::YamlSerializeError { source }
+    }
+
+    pub(crate) fn javascript_parse_error(source: literal::ParseError, path: &Path) -> Error {
+        match source {
+            literal::ParseError::DynamicExport { offset } => Error::DynamicJavaScriptExport {
+                offset,
+                path: path.to_owned(),
+            },
+            source => Error::JavaScriptParseError {
+                source,
+                path: path.to_owned(),
+            },
+        }
+    }
+
+    pub(crate) fn unexpected_manifest_shape_error(key: &str) -> Error {
+        Error::UnexpectedManifestShape {
+            key: key.to_owned(),
+        }
+    }
+
+    pub(crate) fn invalid_plugin_configuration_error(
+        source: serde_json::Error,
+        plugin: &str,
+    ) -> Error {
+        Error::InvalidPluginConfiguration {
+            source,
+            plugin: plugin.to_owned(),
+        }
+    }
+
+    pub(crate) fn duplicate_plugin_error(name: &str) -> Error {
+        Error::DuplicatePlugin {
+            name: name.to_owned(),
+        }
+    }
+
+    pub(crate) fn missing_plugin_error(name: &str) -> Error {
+        Error::MissingPlugin {
+            name: name.to_owned(),
+        }
+    }
+
+    pub(crate) fn unsupported_asset_field_error(plugin: &str, field: &str) -> Error {
+        Error::UnsupportedAssetField {
+            plugin: plugin.to_owned(),
+            field: field.to_owned(),
+        }
+    }
+
+    pub(crate) fn duplicate_asset_path_error(path: &str) -> Error {
+        Error::DuplicateAssetPath {
+            path: path.to_owned(),
+        }
+    }
+
+    pub(crate) fn missing_template_value_error(placeholder: &str) -> Error {
+        Error::MissingTemplateValue {
+            placeholder: placeholder.to_owned(),
+        }
+    }
+
+    pub(crate) fn duplicate_asset_label_error(label: &str) -> Error {
+        Error::DuplicateAssetLabel {
+            label: label.to_owned(),
+        }
+    }
+
+    pub(crate) fn cargo_manifest_parse_error(source: toml::de::Error, path: &Path) -> Error {
+        Error::CargoManifestParseError {
+            source,
+            path: path.to_owned(),
+        }
+    }
+
+    pub(crate) fn cargo_manifest_shape_error(key: &str, path: &Path) -> Error {
+        Error::UnexpectedCargoManifestShape {
+            key: key.to_owned(),
+            path: path.to_owned(),
+        }
+    }
+
     pub(crate) fn file_write_error(source: io::Error, path: &Path) -> Error {
         Error::FileWriteError {
             source,
             path: path.to_owned(),
         }
     }
+
+    pub(crate) fn in_place_stream_error() -> Error {
+        Error::InPlaceStream
+    }
 }
@@ -0,0 +1,282 @@
+//! Typed model of the @semantic-release/github plugin options.
+//!
+//! Options this crate does not model are kept in `extra`, and the order of
+//! keys and the shorthand string forms accepted by the plugin are remembered,
+//! so converting a configuration to these types and back is lossless.
+
+use indexmap::IndexMap;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::asset_list;
+
+/// Options of the @semantic-release/github plugin.
+#[derive(Clone, Debug, Default)]
+pub struct GithubPluginConfig {
+    /// Files to upload to the GitHub release
+    pub assets: Vec<GithubAsset>,
+    /// Every other option, as written
+    pub extra: Map<String, Value>,
+    layout: Layout,
+}
+
+/// A file uploaded to the GitHub release.
+#[derive(Clone, Debug, Default)]
+pub struct GithubAsset {
+    /// Path, or array of globs, of the files to upload
+    pub path: Vec<String>,
+    pub label: Option<String>,
+    pub name: Option<String>,
+    /// Every other property, as written
+    pub extra: Map<String, Value>,
+    layout: Layout,
+}
+
+/// How a value was written, so it can be written back the same way.
+#[derive(Clone, Debug, Default)]
+struct Layout {
+    /// Keys in the order they were read
+    keys: Vec<String>,
+    /// Written as a bare string rather than an object, or for `assets`, an
+    /// array
+    shorthand: bool,
+    /// `path` was written as an array, even if it holds a single glob
+    path_array: bool,
+}
+
+impl GithubAsset {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: vec![path.into()],
+            ..Default::default()
+        }
+    }
+}
+
+impl PartialEq for GithubPluginConfig {
+    fn eq(&self, other: &Self) -> bool {
+        self.assets == other.assets && self.extra == other.extra
+    }
+}
+
+impl PartialEq for GithubAsset {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+            && self.label == other.label
+            && self.name == other.name
+            && self.extra == other.extra
+    }
+}
+
+fn invalid(message: String) -> serde_json::Error {
+    de::Error::custom(message)
+}
+
+fn optional_string(
+    object: &mut Map<String, Value>,
+    key: &str,
+) -> Result<Option<String>, serde_json::Error> {
+    match object.remove(key) {
+        None => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value)),
+        Some(value) => Err(invalid(format!(
+            "expected asset {key} to be a string, found {value}"
+        ))),
+    }
+}
+
+/// Emit `known` and `extra` fields, keeping keys in their original order and
+/// appending new keys at the end.
+fn ordered(keys: &[String], known: Vec<(&str, Option<Value>)>, extra: Map<String, Value>) -> Value {
+    let mut fields: IndexMap<String, Value> = known
+        .into_iter()
+        .filter_map(|(key, value)| Some((key.to_owned(), value?)))
+        .chain(extra)
+        .collect();
+    let mut object: Map<String, Value> = keys
+        .iter()
+        .filter_map(|key| Some((key.clone(), fields.shift_remove(key)?)))
+        .collect();
+    object.extend(fields);
+    Value::Object(object)
+}
+
+impl TryFrom<Value> for GithubAsset {
+    type Error = serde_json::Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let mut object = match value {
+            Value::String(path) => {
+                return Ok(Self {
+                    layout: Layout {
+                        shorthand: true,
+                        ..Default::default()
+                    },
+                    ..Self::new(path)
+                })
+            }
+            Value::Object(object) => object,
+            value => {
+                return Err(invalid(format!(
+                    "expected asset to be a string or object, found {value}"
+                )))
+            }
+        };
+
+        let keys = object.keys().cloned().collect();
+        let (path, path_array) = match object.remove("path") {
+            None => (Vec::new(), false),
+            Some(Value::String(path)) => (vec![path], false),
+            Some(Value::Array(globs)) => (
+                globs
+                    .into_iter()
+                    .map(|glob| match glob {
+                        Value::String(glob) => Ok(glob),
+                        glob => Err(invalid(format!(
+                            "expected asset path to be a string, found {glob}"
+                        ))),
+                    })
+                    .collect::<Result<_, _>>()?,
+                true,
+            ),
+            Some(path) => {
+                return Err(invalid(format!(
+                    "expected asset path to be a string or array, found {path}"
+                )))
+            }
+        };
+
+        Ok(Self {
+            path,
+            label: optional_string(&mut object, "label")?,
+            name: optional_string(&mut object, "name")?,
+            extra: object,
+            layout: Layout {
+                keys,
+                shorthand: false,
+                path_array,
+            },
+        })
+    }
+}
+
+impl From<GithubAsset> for Value {
+    fn from(asset: GithubAsset) -> Self {
+        let GithubAsset {
+            mut path,
+            label,
+            name,
+            extra,
+            layout,
+        } = asset;
+
+        if layout.shorthand
+            && path.len() == 1
+            && label.is_none()
+            && name.is_none()
+            && extra.is_empty()
+        {
+            return Value::String(path.remove(0));
+        }
+
+        let path = match (path.len(), layout.path_array) {
+            (0, false) => None,
+            (1, false) => Some(Value::String(path.remove(0))),
+            _ => Some(Value::Array(path.into_iter().map(Value::String).collect())),
+        };
+        ordered(
+            &layout.keys,
+            vec![
+                ("path", path),
+                ("label", label.map(Value::String)),
+                ("name", name.map(Value::String)),
+            ],
+            extra,
+        )
+    }
+}
+
+impl TryFrom<Value> for GithubPluginConfig {
+    type Error = serde_json::Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let mut object = match value {
+            Value::Object(object) => object,
+            value => {
+                return Err(invalid(format!(
+                    "expected plugin configuration to be an object, found {value}"
+                )))
+            }
+        };
+
+        let keys = object.keys().cloned().collect();
+        let (assets, shorthand) = match object.remove("assets").map(asset_list::into_items) {
+            None => (Vec::new(), false),
+            Some(Ok((assets, shorthand))) => (
+                assets
+                    .into_iter()
+                    .map(GithubAsset::try_from)
+                    .collect::<Result<_, _>>()?,
+                shorthand,
+            ),
+            Some(Err(assets)) => {
+                return Err(invalid(format!(
+                    "expected assets to be a string, object or array, found {assets}"
+                )))
+            }
+        };
+
+        Ok(Self {
+            assets,
+            extra: object,
+            layout: Layout {
+                keys,
+                shorthand,
+                path_array: false,
+            },
+        })
+    }
+}
+
+impl From<GithubPluginConfig> for Value {
+    fn from(config: GithubPluginConfig) -> Self {
+        let GithubPluginConfig {
+            assets,
+            extra,
+            layout,
+        } = config;
+
+        let was_written = layout.keys.iter().any(|key| key == "assets");
+        let assets: Vec<Value> = assets.into_iter().map(Value::from).collect();
+        let assets = match assets.as_slice() {
+            [] if !was_written => None,
+            [_] if layout.shorthand => assets.into_iter().next(),
+            _ => Some(Value::Array(assets)),
+        };
+        ordered(&layout.keys, vec![("assets", assets)], extra)
+    }
+}
+
+impl Serialize for GithubPluginConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Value::from(self.clone()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GithubPluginConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        GithubPluginConfig::try_from(Value::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for GithubAsset {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Value::from(self.clone()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GithubAsset {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        GithubAsset::try_from(Value::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
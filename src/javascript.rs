@@ -0,0 +1,97 @@
+//! Read and write semantic-release manifests exported from JavaScript modules.
+//!
+//! Only configurations exporting a static object literal are supported, e.g.
+//!
+//! ```js
+//! module.exports = {
+//!   branches: ["main"],
+//!   plugins: ["@semantic-release/github"],
+//! };
+//! ```
+//!
+//! or `export default { ... }`. Any expression that would require evaluating
+//! JavaScript (identifiers, function calls, spreads, template substitutions)
+//! is rejected, since the edited literal could not be written back faithfully.
+
+use indexmap::IndexMap;
+
+use crate::literal::{is_identifier_start, Node, NodeKind, ParseError, Parser, Style};
+use crate::patch::patch;
+
+const EXPORT_MARKERS: &[&[&str]] = &[&["module", ".", "exports", "="], &["export", "default"]];
+
+/// A JavaScript module exporting a semantic-release manifest as an object literal.
+#[derive(Debug)]
+pub(crate) struct Module {
+    source: String,
+    /// The exported object literal
+    literal: Node,
+    style: Style,
+}
+
+impl Module {
+    pub(crate) fn parse(
+        source: String,
+    ) -> Result<(Self, IndexMap<String, serde_json::Value>), ParseError> {
+        let start = find_export(&source)?;
+        let mut parser = Parser::new(&source, start);
+        let literal = parser.parse_value()?;
+        let NodeKind::Object(members) = &literal.kind else {
+            return Err(ParseError::DynamicExport { offset: start });
+        };
+        let manifest = members
+            .iter()
+            .map(|member| (member.key.clone(), member.value.to_value()))
+            .collect();
+        let style = Style {
+            quote: parser.quote.unwrap_or('"'),
+            quote_keys: parser.quoted_keys.unwrap_or_default(),
+            indent: crate::literal::detect_indent(&source),
+        };
+
+        Ok((
+            Self {
+                source,
+                literal,
+                style,
+            },
+            manifest,
+        ))
+    }
+
+    /// Render the module with its exported literal replaced by `manifest`.
+    pub(crate) fn print(&self, manifest: &IndexMap<String, serde_json::Value>) -> String {
+        let manifest = serde_json::Value::Object(manifest.clone().into_iter().collect());
+        patch(&self.source, &self.literal, &manifest, &self.style)
+    }
+}
+
+/// Locate the start of the exported expression, skipping comments and strings.
+fn find_export(source: &str) -> Result<usize, ParseError> {
+    let mut parser = Parser::new(source, 0);
+
+    loop {
+        parser.skip_trivia();
+        let Some(c) = parser.peek() else {
+            return Err(ParseError::MissingExport);
+        };
+
+        if is_identifier_start(c) {
+            let checkpoint = parser.position;
+            for marker in EXPORT_MARKERS {
+                parser.position = checkpoint;
+                if marker.iter().all(|token| parser.eat_token(token)) {
+                    parser.skip_trivia();
+                    return Ok(parser.position);
+                }
+            }
+            parser.position = checkpoint;
+            parser.identifier();
+        } else if c == '"' || c == '\'' || c == '`' {
+            // Strings elsewhere in the module may contain anything
+            parser.skip_string(c);
+        } else {
+            parser.bump();
+        }
+    }
+}
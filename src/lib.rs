@@ -3,23 +3,85 @@
 
 use std::{
     collections::HashSet,
+    fs::{self, File, OpenOptions},
+    io::Read,
+    path::Path,
+};
+use std::{
     io::{self, BufWriter, Write},
     path::PathBuf,
     str::FromStr,
 };
-use std::{fs::File, io::Read, path::Path};
 
 use indexmap::{map::Entry, IndexMap};
-use log::debug;
+use log::{debug, warn};
 
+mod asset_list;
+mod asset_plugin;
+mod cargo;
 mod error;
+mod github;
+mod javascript;
+mod literal;
+mod patch;
+mod pattern;
+mod template;
 
+pub use crate::asset_plugin::{AssetPlugin, AssetSchema};
+pub use crate::cargo::find_cargo_binaries;
 use crate::error::Error;
+pub use crate::github::{GithubAsset, GithubPluginConfig};
+pub use crate::pattern::{AssetMatcher, AssetPattern, PatternError, REGEX_PREFIX};
+pub use crate::template::{
+    AssetTemplate, BINARY_PLACEHOLDER, DEFAULT_BINARIES_LABEL_TEMPLATE, DEFAULT_LABEL_TEMPLATE,
+    DEFAULT_PATH_TEMPLATE, TARGET_PLACEHOLDER,
+};
 
 #[derive(Debug)]
 pub enum WriteTo {
     Stdout,
+    /// Atomically replace the manifest
     InPlace,
+    /// Atomically replace the manifest, first copying the prior version to
+    /// the manifest path with `suffix` appended
+    InPlaceWithBackup {
+        suffix: String,
+    },
+    /// Atomically write to another file, leaving the manifest untouched
+    File(PathBuf),
+    /// A unified diff of the pending changes, on stdout
+    Diff,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ManifestFormat {
+    Json,
+    Yaml,
+    JavaScript,
+}
+
+impl ManifestFormat {
+    fn detect(path: &Path, contents: &str) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("yaml") | Some("yml") => ManifestFormat::Yaml,
+            Some("js") | Some("cjs") | Some("mjs") => ManifestFormat::JavaScript,
+            Some(_) => ManifestFormat::Json,
+            // An extensionless `.releaserc` may be written in either format
+            None => ManifestFormat::sniff(contents),
+        }
+    }
+
+    /// Infer the format of a manifest from its contents.
+    ///
+    /// Contents starting with `{` or `[` are JSON. YAML flow collections
+    /// start the same way, but reading them as JSON reports a JSON syntax
+    /// error instead of silently re-emitting the file as block YAML.
+    pub fn sniff(contents: &str) -> Self {
+        match contents.trim_start().chars().next() {
+            Some('{') | Some('[') => ManifestFormat::Json,
+            _ => ManifestFormat::Yaml,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -43,21 +105,128 @@ impl FromStr for SemanticReleaseManifest {
     }
 }
 
+/// The release asset property matched against a whitelist or blacklist.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AssetField {
+    /// The label; assets without one are left as they are
+    #[default]
+    Label,
+    Path,
+    Name,
+    /// Any of `label`, `path` or `name`
+    Any,
+}
+
+impl AssetField {
+    /// Values of this field, understanding every asset shape accepted by
+    /// @semantic-release/github: a plain path string, or an object whose
+    /// `path` is a glob or an array of globs.
+    fn values(self, asset: &serde_json::Value) -> Vec<&str> {
+        let keys: &[&str] = match self {
+            AssetField::Label => &["label"],
+            AssetField::Path => &["path"],
+            AssetField::Name => &["name"],
+            AssetField::Any => &["label", "path", "name"],
+        };
+        keys.iter()
+            .flat_map(|key| match (asset, *key) {
+                (serde_json::Value::String(path), "path") => vec![path.as_str()],
+                (serde_json::Value::Object(object), key) => match object.get(key) {
+                    Some(serde_json::Value::String(value)) => vec![value.as_str()],
+                    Some(serde_json::Value::Array(globs)) if key == "path" => {
+                        globs.iter().filter_map(|glob| glob.as_str()).collect()
+                    }
+                    _ => vec![],
+                },
+                _ => vec![],
+            })
+            .collect()
+    }
+}
+
+/// Which assets a whitelist or blacklist is matched against.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MatchOptions {
+    /// Plugin whose assets are edited
+    pub plugin: AssetPlugin,
+    /// Asset property matched against each entry
+    pub field: AssetField,
+}
+
+impl MatchOptions {
+    /// Match the assets of `plugin` by its [default field](AssetPlugin::default_field).
+    pub fn for_plugin(plugin: AssetPlugin) -> Self {
+        Self {
+            plugin,
+            field: plugin.default_field(),
+        }
+    }
+}
+
+/// Name of the package.json property holding a semantic-release manifest.
+const PACKAGE_JSON_RELEASE_KEY: &str = "release";
+
+/// The file a semantic-release manifest was read from, and how to write it back.
+#[derive(Debug)]
+enum ManifestSource {
+    /// A JSON file containing only the manifest
+    Json,
+    /// A YAML file containing only the manifest
+    Yaml,
+    /// The `release` property of this package.json
+    PackageJson(IndexMap<String, serde_json::Value>),
+    /// The object literal exported by this JavaScript module
+    JavaScript(javascript::Module),
+}
+
+impl ManifestSource {
+    fn format(&self) -> ManifestFormat {
+        match self {
+            ManifestSource::Json | ManifestSource::PackageJson(_) => ManifestFormat::Json,
+            ManifestSource::Yaml => ManifestFormat::Yaml,
+            ManifestSource::JavaScript(_) => ManifestFormat::JavaScript,
+        }
+    }
+}
+
 pub struct SemanticReleaseConfiguration {
     manifest: SemanticReleaseManifest,
-    manifest_path: PathBuf,
+    /// File the manifest was read from, or `None` for a stream
+    manifest_path: Option<PathBuf>,
+    /// Contents of the configuration file as read
+    original: String,
+    source: ManifestSource,
     dirty: ModifiedFlag,
 }
 
+fn is_package_json(path: &Path) -> bool {
+    path.file_name()
+        .map(|file_name| file_name == "package.json")
+        .unwrap_or_default()
+}
+
+/// Names a manifest read by [`SemanticReleaseConfiguration::from_reader`] in
+/// messages.
+const STREAM_NAME: &str = "<stdin>";
+
 fn plugin_name(plugin: &serde_json::Value) -> Option<&str> {
     match plugin {
         serde_json::Value::String(name) => Some(name.as_str()),
-        serde_json::Value::Array(array) => array.get(0).and_then(|value| value.as_str()),
+        serde_json::Value::Array(array) => array.first().and_then(|value| value.as_str()),
         _ => None,
     }
 }
 
 fn plugin_configuration(
+    plugin: &serde_json::Value,
+) -> Option<&serde_json::Map<String, serde_json::Value>> {
+    match plugin {
+        serde_json::Value::Array(array) => array.get(1).and_then(|value| value.as_object()),
+        _ => None,
+    }
+}
+
+fn plugin_configuration_mut(
     plugin: &mut serde_json::Value,
 ) -> Option<&mut serde_json::Map<String, serde_json::Value>> {
     match plugin {
@@ -66,45 +235,469 @@ fn plugin_configuration(
     }
 }
 
+/// Like [`plugin_configuration_mut`], but converts a string-form plugin into a
+/// `[name, {}]` pair so it can be configured.
+fn plugin_configuration_or_insert(
+    plugin: &mut serde_json::Value,
+) -> Option<&mut serde_json::Map<String, serde_json::Value>> {
+    if let serde_json::Value::String(name) = plugin {
+        let name = std::mem::take(name);
+        *plugin = serde_json::Value::Array(vec![name.into()]);
+    }
+    let array = plugin.as_array_mut()?;
+    if array.len() == 1 {
+        array.push(serde_json::Value::Object(serde_json::Map::new()));
+    }
+    array.get_mut(1).and_then(|value| value.as_object_mut())
+}
+
+/// An asset configured for an [`AssetPlugin`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Asset<'a> {
+    /// Path, or array of globs, of the files to upload
+    pub path: Vec<&'a str>,
+    pub label: Option<&'a str>,
+    pub name: Option<&'a str>,
+}
+
+/// A semantic-release plugin, written as a name or a `[name, options]` pair.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Plugin<'a> {
+    pub name: &'a str,
+    pub configuration: Option<&'a serde_json::Map<String, serde_json::Value>>,
+}
+
+/// What [`SemanticReleaseManifest::add_asset`] does when an asset with the
+/// same label already exists.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OnDuplicateLabel {
+    Refuse,
+    Update,
+}
+
 impl SemanticReleaseManifest {
-    pub fn apply_whitelist(&mut self, whitelist: HashSet<String>) -> ModifiedFlag {
+    pub fn from_yaml_str(s: &str) -> Result<Self, serde_yaml::Error> {
+        Ok(Self {
+            inner: serde_yaml::from_str(s)?,
+        })
+    }
+
+    pub fn to_yaml_string(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(&self.inner)
+    }
+
+    /// The release assets configured for the @semantic-release/github plugin.
+    pub fn assets(&self) -> Vec<Asset<'_>> {
+        self.assets_in(AssetPlugin::Github)
+    }
+
+    /// The assets configured for `plugin`.
+    pub fn assets_in(&self, plugin: AssetPlugin) -> Vec<Asset<'_>> {
+        let plugins = self
+            .inner
+            .get("plugins")
+            .and_then(|plugins| plugins.as_array())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        plugins
+            .iter()
+            .filter(|candidate| plugin_name(candidate) == Some(plugin.name()))
+            .filter_map(|plugin| plugin_configuration(plugin)?.get("assets"))
+            .flat_map(asset_list::items)
+            .filter(|asset| asset.is_string() || asset.is_object())
+            .map(|asset| Asset {
+                path: AssetField::Path.values(asset),
+                label: AssetField::Label.values(asset).first().copied(),
+                name: AssetField::Name.values(asset).first().copied(),
+            })
+            .collect()
+    }
+
+    /// Keep only the assets of `options.plugin` for which `keep` returns true.
+    ///
+    /// `keep` is told whether `matcher` selected the asset by `options.field`.
+    fn retain_assets(
+        &mut self,
+        MatchOptions { plugin, field }: MatchOptions,
+        matcher: impl AssetMatcher,
+        keep: impl Fn(bool) -> bool,
+    ) -> ModifiedFlag {
         let mut dirty = ModifiedFlag::Unmodified;
 
         if let Entry::Occupied(mut entry) = self.inner.entry("plugins".to_owned()) {
             if let Some(plugins) = entry.get_mut().as_array_mut() {
-                for plugin in plugins {
-                    if plugin_name(plugin) != Some("@semantic-release/github") {
+                for candidate in plugins {
+                    if plugin_name(candidate) != Some(plugin.name()) {
                         continue;
                     }
 
-                    if let Some(assets) = plugin_configuration(plugin)
+                    let Some(assets) = plugin_configuration_mut(candidate)
                         .and_then(|settings| settings.get_mut("assets"))
-                        .and_then(|assets| assets.as_array_mut())
-                    {
-                        assets.retain(|asset| {
-                            let label = asset
-                                .as_object()
-                                .and_then(|asset| asset.get("label"))
-                                .and_then(|label| label.as_str());
-                            match label {
-                                Some(label) => {
-                                    let keep = whitelist.contains(label);
-                                    if !keep {
-                                        dirty = ModifiedFlag::Modified;
-                                    }
-                                    keep
-                                }
-                                // Not sure what this is, so pass it through unchanged
-                                None => true,
-                            }
-                        });
+                    else {
+                        continue;
                     };
+
+                    let should_keep = |asset: &serde_json::Value| {
+                        let values = field.values(asset);
+                        if values.is_empty() {
+                            // Not sure what this is, so pass it through unchanged
+                            return true;
+                        }
+                        let keep = keep(values.iter().any(|value| matcher.is_match(value)));
+                        if !keep {
+                            dirty = ModifiedFlag::Modified;
+                        }
+                        keep
+                    };
+
+                    asset_list::retain(assets, should_keep);
                 }
             }
         };
 
         dirty
     }
+
+    /// The configured plugins, in order.
+    pub fn plugins(&self) -> Vec<Plugin<'_>> {
+        self.inner
+            .get("plugins")
+            .and_then(|plugins| plugins.as_array())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|plugin| {
+                Some(Plugin {
+                    name: plugin_name(plugin)?,
+                    configuration: plugin_configuration(plugin),
+                })
+            })
+            .collect()
+    }
+
+    /// The first plugin named `name`.
+    pub fn plugin(&self, name: &str) -> Option<Plugin<'_>> {
+        self.plugins()
+            .into_iter()
+            .find(|plugin| plugin.name == name)
+    }
+
+    /// Options of the first plugin named `name`, converting a string-form
+    /// plugin into a `[name, {}]` pair so it can be configured.
+    pub fn plugin_mut(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut serde_json::Map<String, serde_json::Value>> {
+        self.inner
+            .get_mut("plugins")?
+            .as_array_mut()?
+            .iter_mut()
+            .find(|plugin| plugin_name(plugin) == Some(name))
+            .and_then(plugin_configuration_or_insert)
+    }
+
+    /// Insert plugin `name` at `index`, or at the end if `index` is past the
+    /// last plugin. Without `configuration`, the plugin is written in string
+    /// form.
+    pub fn insert_plugin(
+        &mut self,
+        index: usize,
+        name: &str,
+        configuration: Option<serde_json::Map<String, serde_json::Value>>,
+    ) -> Result<(), Error> {
+        if self.plugin(name).is_some() {
+            return Err(Error::duplicate_plugin_error(name));
+        }
+
+        let plugins = self.plugins_mut()?;
+        let plugin = match configuration {
+            Some(configuration) => serde_json::Value::Array(vec![
+                name.into(),
+                serde_json::Value::Object(configuration),
+            ]),
+            None => name.into(),
+        };
+        plugins.insert(index.min(plugins.len()), plugin);
+        Ok(())
+    }
+
+    /// Remove every plugin named `name`.
+    pub fn remove_plugin(&mut self, name: &str) -> Result<(), Error> {
+        let plugins = self
+            .inner
+            .get_mut("plugins")
+            .and_then(|plugins| plugins.as_array_mut())
+            .ok_or_else(|| Error::missing_plugin_error(name))?;
+
+        let count = plugins.len();
+        plugins.retain(|plugin| plugin_name(plugin) != Some(name));
+        match plugins.len() == count {
+            true => Err(Error::missing_plugin_error(name)),
+            false => Ok(()),
+        }
+    }
+
+    /// Move the first plugin named `name` to `index`, or to the end if `index`
+    /// is past the last plugin.
+    pub fn move_plugin(&mut self, name: &str, index: usize) -> Result<ModifiedFlag, Error> {
+        let plugins = self
+            .inner
+            .get_mut("plugins")
+            .and_then(|plugins| plugins.as_array_mut())
+            .ok_or_else(|| Error::missing_plugin_error(name))?;
+        let current = plugins
+            .iter()
+            .position(|plugin| plugin_name(plugin) == Some(name))
+            .ok_or_else(|| Error::missing_plugin_error(name))?;
+
+        let index = index.min(plugins.len() - 1);
+        if current == index {
+            return Ok(ModifiedFlag::Unmodified);
+        }
+        let plugin = plugins.remove(current);
+        plugins.insert(index, plugin);
+        Ok(ModifiedFlag::Modified)
+    }
+
+    /// Set `options` on the first plugin named `name`, keeping its other
+    /// options.
+    pub fn set_plugin_options(
+        &mut self,
+        name: &str,
+        options: serde_json::Map<String, serde_json::Value>,
+    ) -> Result<ModifiedFlag, Error> {
+        let Some(plugin) = self.plugin(name) else {
+            return Err(Error::missing_plugin_error(name));
+        };
+        let unchanged = options.iter().all(|(key, value)| {
+            plugin
+                .configuration
+                .and_then(|configuration| configuration.get(key))
+                == Some(value)
+        });
+        if unchanged {
+            return Ok(ModifiedFlag::Unmodified);
+        }
+
+        self.plugin_mut(name)
+            .ok_or_else(|| Error::unexpected_manifest_shape_error(name))?
+            .extend(options);
+        Ok(ModifiedFlag::Modified)
+    }
+
+    /// The `plugins` array, created if absent.
+    fn plugins_mut(&mut self) -> Result<&mut Vec<serde_json::Value>, Error> {
+        self.inner
+            .entry("plugins".to_owned())
+            .or_insert_with(|| serde_json::Value::Array(Vec::new()))
+            .as_array_mut()
+            .ok_or_else(|| Error::unexpected_manifest_shape_error("plugins"))
+    }
+
+    /// Options of the @semantic-release/github plugin, if it is configured.
+    pub fn github_plugin_config(&self) -> Result<Option<GithubPluginConfig>, Error> {
+        let Some(plugin) = self.plugin(AssetPlugin::Github.name()) else {
+            return Ok(None);
+        };
+
+        match plugin.configuration {
+            Some(configuration) => {
+                GithubPluginConfig::try_from(serde_json::Value::Object(configuration.clone()))
+                    .map(Some)
+                    .map_err(|err| {
+                        Error::invalid_plugin_configuration_error(err, AssetPlugin::Github.name())
+                    })
+            }
+            None => Ok(Some(GithubPluginConfig::default())),
+        }
+    }
+
+    /// Replace the options of the @semantic-release/github plugin, adding the
+    /// plugin if necessary.
+    pub fn set_github_plugin_config(
+        &mut self,
+        config: GithubPluginConfig,
+    ) -> Result<ModifiedFlag, Error> {
+        let serde_json::Value::Object(config) = serde_json::Value::from(config) else {
+            unreachable!("plugin configuration serializes to an object");
+        };
+
+        match self.plugin(AssetPlugin::Github.name()) {
+            None => {
+                self.insert_plugin(usize::MAX, AssetPlugin::Github.name(), Some(config))?;
+                return Ok(ModifiedFlag::Modified);
+            }
+            Some(plugin) if plugin.configuration == Some(&config) => {
+                return Ok(ModifiedFlag::Unmodified)
+            }
+            // Keep the string form rather than adding an empty configuration
+            Some(plugin) if plugin.configuration.is_none() && config.is_empty() => {
+                return Ok(ModifiedFlag::Unmodified)
+            }
+            Some(_) => {}
+        }
+
+        *self
+            .plugin_mut(AssetPlugin::Github.name())
+            .ok_or_else(|| Error::unexpected_manifest_shape_error(AssetPlugin::Github.name()))? =
+            config;
+        Ok(ModifiedFlag::Modified)
+    }
+
+    /// Append an asset to the @semantic-release/github plugin, creating the
+    /// plugin and its `assets` array if necessary.
+    pub fn add_asset(
+        &mut self,
+        path: &str,
+        label: &str,
+        name: Option<&str>,
+        on_duplicate: OnDuplicateLabel,
+    ) -> Result<ModifiedFlag, Error> {
+        self.add_asset_in(AssetPlugin::Github, path, Some(label), name, on_duplicate)
+    }
+
+    /// Append an asset to `plugin`, creating the plugin and its `assets`
+    /// array if necessary.
+    ///
+    /// An existing asset with the same label, or with the same path when no
+    /// label is given, is a duplicate.
+    pub fn add_asset_in(
+        &mut self,
+        plugin: AssetPlugin,
+        path: &str,
+        label: Option<&str>,
+        name: Option<&str>,
+        on_duplicate: OnDuplicateLabel,
+    ) -> Result<ModifiedFlag, Error> {
+        let asset = plugin.new_asset(path, label, name)?;
+        let is_duplicate = |existing: &serde_json::Value| match label {
+            Some(label) => AssetField::Label.values(existing) == [label],
+            None => AssetField::Path.values(existing) == [path],
+        };
+
+        let duplicate = self.assets_in(plugin).iter().any(|existing| match label {
+            Some(label) => existing.label == Some(label),
+            None => existing.path == [path],
+        });
+        if duplicate && on_duplicate == OnDuplicateLabel::Refuse {
+            return Err(match label {
+                Some(label) => Error::duplicate_asset_label_error(label),
+                None => Error::duplicate_asset_path_error(path),
+            });
+        }
+
+        if self.plugin(plugin.name()).is_none() {
+            self.insert_plugin(usize::MAX, plugin.name(), None)?;
+        }
+        let assets = self
+            .plugin_mut(plugin.name())
+            .ok_or_else(|| Error::unexpected_manifest_shape_error(plugin.name()))?
+            .entry("assets")
+            .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+        let assets = asset_list::items_mut(assets)
+            .ok_or_else(|| Error::unexpected_manifest_shape_error("assets"))?;
+
+        let Some(existing) = assets.iter_mut().find(|existing| is_duplicate(existing)) else {
+            assets.push(asset);
+            return Ok(ModifiedFlag::Modified);
+        };
+        let serde_json::Value::Object(existing) = existing else {
+            // A bare path has nothing to update
+            return Ok(ModifiedFlag::Unmodified);
+        };
+
+        let original = existing.clone();
+        existing.insert("path".to_owned(), path.into());
+        match name {
+            Some(name) => {
+                existing.insert("name".to_owned(), name.into());
+            }
+            None => existing.retain(|key, _| key != "name"),
+        }
+        match *existing == original {
+            true => Ok(ModifiedFlag::Unmodified),
+            false => Ok(ModifiedFlag::Modified),
+        }
+    }
+
+    /// Replace the assets of `plugin` with one asset per binary and target,
+    /// expanded from `template`.
+    pub fn generate_assets_in(
+        &mut self,
+        plugin: AssetPlugin,
+        template: &AssetTemplate,
+        binaries: &[&str],
+        targets: &[&str],
+    ) -> Result<ModifiedFlag, Error> {
+        let binaries: Vec<Option<&str>> = match binaries.is_empty() {
+            true => vec![None],
+            false => binaries.iter().copied().map(Some).collect(),
+        };
+        let mut labels = HashSet::new();
+        let mut assets = Vec::with_capacity(binaries.len() * targets.len());
+        for binary in binaries {
+            for target in targets {
+                let asset = template.expand(binary, target)?;
+                if let Some(label) = &asset.label {
+                    if !labels.insert(label.clone()) {
+                        return Err(Error::duplicate_asset_label_error(label));
+                    }
+                }
+                assets.push(plugin.new_asset(
+                    &asset.path,
+                    asset.label.as_deref(),
+                    asset.name.as_deref(),
+                )?);
+            }
+        }
+        let assets = serde_json::Value::Array(assets);
+
+        let unchanged = self
+            .plugin(plugin.name())
+            .and_then(|plugin| plugin.configuration)
+            .and_then(|configuration| configuration.get("assets"))
+            == Some(&assets);
+        if unchanged {
+            return Ok(ModifiedFlag::Unmodified);
+        }
+
+        if self.plugin(plugin.name()).is_none() {
+            self.insert_plugin(usize::MAX, plugin.name(), None)?;
+        }
+        self.plugin_mut(plugin.name())
+            .ok_or_else(|| Error::unexpected_manifest_shape_error(plugin.name()))?
+            .insert("assets".to_owned(), assets);
+        Ok(ModifiedFlag::Modified)
+    }
+
+    /// Keep only the GitHub assets whose label is selected by `whitelist`.
+    pub fn apply_whitelist(&mut self, whitelist: impl AssetMatcher) -> ModifiedFlag {
+        self.apply_whitelist_with(MatchOptions::default(), whitelist)
+    }
+
+    /// Keep only the assets selected by `whitelist`.
+    pub fn apply_whitelist_with(
+        &mut self,
+        options: MatchOptions,
+        whitelist: impl AssetMatcher,
+    ) -> ModifiedFlag {
+        self.retain_assets(options, whitelist, |selected| selected)
+    }
+
+    /// Remove the GitHub assets whose label is selected by `blacklist`.
+    pub fn apply_blacklist(&mut self, blacklist: impl AssetMatcher) -> ModifiedFlag {
+        self.apply_blacklist_with(MatchOptions::default(), blacklist)
+    }
+
+    /// Remove the assets selected by `blacklist`.
+    pub fn apply_blacklist_with(
+        &mut self,
+        options: MatchOptions,
+        blacklist: impl AssetMatcher,
+    ) -> ModifiedFlag {
+        self.retain_assets(options, blacklist, |selected| !selected)
+    }
 }
 
 impl std::fmt::Display for SemanticReleaseManifest {
@@ -114,8 +707,9 @@ impl std::fmt::Display for SemanticReleaseManifest {
 }
 
 impl SemanticReleaseConfiguration {
-    // FIXME: use find-semantic-release-manifest.
-    // For now, assume the semantic-release configuration is a .releaserc.json and document the limitation
+    /// Read a manifest from a file: the `release` property of a package.json,
+    /// or a JSON, YAML or JavaScript configuration file as its name and
+    /// contents indicate.
     pub fn read_from_file(semantic_release_manifest_path: &Path) -> Result<Self, Error> {
         debug!(
             "Reading semantic-release configuration from file {:?}",
@@ -137,47 +731,389 @@ impl SemanticReleaseConfiguration {
             .read_to_string(&mut string)
             .map_err(|err| Error::file_read_error(err, semantic_release_manifest_path))?;
 
+        if is_package_json(semantic_release_manifest_path) {
+            return Self::read_from_package_json(string, semantic_release_manifest_path);
+        }
+
+        let format = ManifestFormat::detect(semantic_release_manifest_path, &string);
+        Self::parse(string, Some(semantic_release_manifest_path), format)
+    }
+
+    /// Read a manifest in the given format from a stream, such as stdin.
+    ///
+    /// The result can be written anywhere except in-place.
+    pub fn from_reader(mut reader: impl Read, format: ManifestFormat) -> Result<Self, Error> {
+        let mut string = String::new();
+        reader
+            .read_to_string(&mut string)
+            .map_err(|err| Error::file_read_error(err, Path::new(STREAM_NAME)))?;
+        Self::parse(string, None, format)
+    }
+
+    fn parse(
+        string: String,
+        manifest_path: Option<&Path>,
+        format: ManifestFormat,
+    ) -> Result<Self, Error> {
+        debug!("Reading semantic-release configuration as {:?}", format);
+        let path = manifest_path.unwrap_or(Path::new(STREAM_NAME));
+        let (manifest, source) = match format {
+            ManifestFormat::Json => (
+                SemanticReleaseManifest::from_str(&string)
+                    .map_err(|err| Error::file_parse_error(err, path))?,
+                ManifestSource::Json,
+            ),
+            ManifestFormat::Yaml => (
+                SemanticReleaseManifest::from_yaml_str(&string)
+                    .map_err(|err| Error::yaml_parse_error(err, path))?,
+                ManifestSource::Yaml,
+            ),
+            ManifestFormat::JavaScript => {
+                let (module, inner) = javascript::Module::parse(string.clone())
+                    .map_err(|err| Error::javascript_parse_error(err, path))?;
+                (
+                    SemanticReleaseManifest { inner },
+                    ManifestSource::JavaScript(module),
+                )
+            }
+        };
+
         Ok(Self {
-            manifest: SemanticReleaseManifest::from_str(&string)
-                .map_err(|err| Error::file_parse_error(err, semantic_release_manifest_path))?,
-            manifest_path: semantic_release_manifest_path.to_owned(),
+            manifest,
+            manifest_path: manifest_path.map(Path::to_owned),
+            original: string,
+            source,
             dirty: ModifiedFlag::Unmodified,
         })
     }
 
+    fn read_from_package_json(contents: String, package_json_path: &Path) -> Result<Self, Error> {
+        let mut package: IndexMap<String, serde_json::Value> = serde_json::from_str(&contents)
+            .map_err(|err| Error::file_parse_error(err, package_json_path))?;
+
+        let release = match package.get_mut(PACKAGE_JSON_RELEASE_KEY) {
+            Some(serde_json::Value::Object(release)) => std::mem::take(release),
+            _ => return Err(Error::missing_release_property_error(package_json_path)),
+        };
+
+        Ok(Self {
+            manifest: SemanticReleaseManifest {
+                inner: release.into_iter().collect(),
+            },
+            manifest_path: Some(package_json_path.to_owned()),
+            original: contents,
+            source: ManifestSource::PackageJson(package),
+            dirty: ModifiedFlag::Unmodified,
+        })
+    }
+
+    /// Render the complete contents of the configuration file.
+    fn serialize(&self) -> Result<String, Error> {
+        match &self.source {
+            ManifestSource::Json => self.serialize_json(&self.manifest.inner),
+            ManifestSource::PackageJson(package) => {
+                // Re-inserting an existing key keeps its original position
+                let mut package = package.clone();
+                package.insert(
+                    PACKAGE_JSON_RELEASE_KEY.to_owned(),
+                    serde_json::Value::Object(self.manifest.inner.clone().into_iter().collect()),
+                );
+                self.serialize_json(&package)
+            }
+            // serde_yaml terminates its output with a newline already
+            ManifestSource::Yaml => {
+                serde_yaml::to_string(&self.manifest.inner).map_err(Error::yaml_serialize_error)
+            }
+            ManifestSource::JavaScript(module) => Ok(module.print(&self.manifest.inner)),
+        }
+    }
+
+    /// Render `document` as a patch of the original JSON text, so formatting
+    /// of untouched regions is preserved.
+    fn serialize_json(
+        &self,
+        document: &IndexMap<String, serde_json::Value>,
+    ) -> Result<String, Error> {
+        let document = serde_json::Value::Object(document.clone().into_iter().collect());
+        match literal::parse_document(&self.original) {
+            Ok(root) => Ok(patch::patch(
+                &self.original,
+                &root,
+                &document,
+                &literal::Style::json(&self.original),
+            )),
+            Err(err) => {
+                debug!("Unable to patch original JSON, re-serializing: {}", err);
+                let mut string =
+                    serde_json::to_string_pretty(&document).map_err(Error::file_serialize_error)?;
+                string.push('\n');
+                Ok(string)
+            }
+        }
+    }
+
+    /// Render a unified diff from the file as read to the edited file.
+    pub fn diff(&self) -> Result<String, Error> {
+        let edited = self.serialize()?;
+        let path = self.display_path().display().to_string();
+        Ok(similar::TextDiff::from_lines(&self.original, &edited)
+            .unified_diff()
+            .header(&path, &path)
+            .to_string())
+    }
+
     fn write(&mut self, mut w: impl Write) -> Result<(), Error> {
         debug!(
             "Writing semantic-release configuration to file {:?}",
-            self.manifest_path
+            self.display_path()
         );
-        serde_json::to_writer_pretty(&mut w, &self.manifest.inner)
-            .map_err(Error::file_serialize_error)?;
-        w.write_all(b"\n")
-            .map_err(|err| Error::file_write_error(err, &self.manifest_path))?;
+        w.write_all(self.serialize()?.as_bytes())
+            .map_err(|err| Error::file_write_error(err, self.display_path()))?;
         w.flush()
-            .map_err(|err| Error::file_write_error(err, &self.manifest_path))?;
+            .map_err(|err| Error::file_write_error(err, self.display_path()))?;
 
         Ok(())
     }
 
+    /// The path the manifest was read from, or a name for a stream.
+    pub fn display_path(&self) -> &Path {
+        self.manifest_path
+            .as_deref()
+            .unwrap_or(Path::new(STREAM_NAME))
+    }
+
+    pub fn format(&self) -> ManifestFormat {
+        self.source.format()
+    }
+
+    pub fn manifest(&self) -> &SemanticReleaseManifest {
+        &self.manifest
+    }
+
+    pub fn modified(&self) -> ModifiedFlag {
+        self.dirty.clone()
+    }
+
+    /// Write the manifest to `write_to` if it was edited. A [`WriteTo::File`]
+    /// destination is written regardless, so it never holds a stale copy.
     pub fn write_if_modified(&mut self, write_to: WriteTo) -> Result<(), Error> {
-        match self.dirty {
-            ModifiedFlag::Unmodified => Ok(()),
-            ModifiedFlag::Modified => match write_to {
-                WriteTo::Stdout => self.write(io::stdout()),
-                WriteTo::InPlace => {
-                    let file = File::create(&self.manifest_path)
-                        .map_err(|err| Error::file_open_error(err, &self.manifest_path))?;
-                    self.write(BufWriter::new(file))
+        match (&self.dirty, &write_to) {
+            (ModifiedFlag::Unmodified, WriteTo::File(_)) | (ModifiedFlag::Modified, _) => {
+                self.write_to(write_to)
+            }
+            (ModifiedFlag::Unmodified, _) => Ok(()),
+        }
+    }
+
+    /// Write the manifest to `write_to`, whether or not it was edited.
+    pub fn write_to(&mut self, write_to: WriteTo) -> Result<(), Error> {
+        match write_to {
+            WriteTo::Stdout => self.write(io::stdout()),
+            WriteTo::Diff => {
+                let diff = self.diff()?;
+                let mut stdout = io::stdout();
+                stdout
+                    .write_all(diff.as_bytes())
+                    .and_then(|()| stdout.flush())
+                    .map_err(|err| Error::file_write_error(err, self.display_path()))
+            }
+            WriteTo::InPlace => self.write_in_place(None),
+            WriteTo::InPlaceWithBackup { suffix } => self.write_in_place(Some(&suffix)),
+            WriteTo::File(path) => self.write_atomically(&path, None),
+        }
+    }
+
+    fn write_in_place(&mut self, backup_suffix: Option<&str>) -> Result<(), Error> {
+        let Some(path) = self.manifest_path.clone() else {
+            return Err(Error::in_place_stream_error());
+        };
+        if self.format() == ManifestFormat::Yaml {
+            // YAML is re-emitted in full rather than patched
+            warn!(
+                "Rewriting {:?} drops its comments and custom formatting",
+                path
+            );
+        }
+        self.write_atomically(&path, backup_suffix)
+    }
+
+    /// Write to a temporary file beside `target` and rename it over `target`,
+    /// so a failure never leaves a truncated file behind.
+    fn write_atomically(
+        &mut self,
+        target: &Path,
+        backup_suffix: Option<&str>,
+    ) -> Result<(), Error> {
+        let exists = target.exists();
+        // Replace the target of a symlink rather than the link itself
+        let path = match exists {
+            true => fs::canonicalize(target).map_err(|err| Error::file_open_error(err, target))?,
+            false => target.to_owned(),
+        };
+        let permissions = match exists {
+            true => Some(
+                fs::metadata(&path)
+                    .map_err(|err| Error::file_open_error(err, &path))?
+                    .permissions(),
+            ),
+            false => None,
+        };
+
+        let (temporary_path, file) = create_temporary_file(&path)?;
+        let result = self
+            .write(BufWriter::new(&file))
+            .and_then(|()| {
+                file.sync_all()
+                    .and_then(|()| match permissions {
+                        Some(permissions) => fs::set_permissions(&temporary_path, permissions),
+                        None => Ok(()),
+                    })
+                    .map_err(|err| Error::file_write_error(err, &temporary_path))
+            })
+            .and_then(|()| match backup_suffix {
+                Some(suffix) => {
+                    let mut backup_path = path.clone().into_os_string();
+                    backup_path.push(suffix);
+                    let backup_path = PathBuf::from(backup_path);
+                    fs::copy(&path, &backup_path)
+                        .map(|_| ())
+                        .map_err(|err| Error::file_write_error(err, &backup_path))
                 }
-            },
+                None => Ok(()),
+            })
+            .and_then(|()| {
+                fs::rename(&temporary_path, &path)
+                    .map_err(|err| Error::file_write_error(err, &path))
+            });
+
+        if result.is_err() {
+            // Best effort; the original error is more useful than this one
+            let _ = fs::remove_file(&temporary_path);
         }
+        result
     }
 
-    pub fn apply_whitelist(&mut self, to_remove: HashSet<String>) {
-        let modified = self.manifest.apply_whitelist(to_remove);
+    /// Record that an edit of the manifest made `modified`.
+    fn mark(&mut self, modified: ModifiedFlag) {
         if modified == ModifiedFlag::Modified {
             self.dirty = ModifiedFlag::Modified;
         }
     }
+
+    pub fn apply_whitelist(&mut self, to_keep: impl AssetMatcher) {
+        self.apply_whitelist_with(MatchOptions::default(), to_keep)
+    }
+
+    pub fn apply_whitelist_with(&mut self, options: MatchOptions, to_keep: impl AssetMatcher) {
+        let modified = self.manifest.apply_whitelist_with(options, to_keep);
+        self.mark(modified);
+    }
+
+    pub fn apply_blacklist(&mut self, to_remove: impl AssetMatcher) {
+        self.apply_blacklist_with(MatchOptions::default(), to_remove)
+    }
+
+    pub fn apply_blacklist_with(&mut self, options: MatchOptions, to_remove: impl AssetMatcher) {
+        let modified = self.manifest.apply_blacklist_with(options, to_remove);
+        self.mark(modified);
+    }
+
+    pub fn add_asset(
+        &mut self,
+        path: &str,
+        label: &str,
+        name: Option<&str>,
+        on_duplicate: OnDuplicateLabel,
+    ) -> Result<(), Error> {
+        self.add_asset_in(AssetPlugin::Github, path, Some(label), name, on_duplicate)
+    }
+
+    pub fn add_asset_in(
+        &mut self,
+        plugin: AssetPlugin,
+        path: &str,
+        label: Option<&str>,
+        name: Option<&str>,
+        on_duplicate: OnDuplicateLabel,
+    ) -> Result<(), Error> {
+        let modified = self
+            .manifest
+            .add_asset_in(plugin, path, label, name, on_duplicate)?;
+        self.mark(modified);
+        Ok(())
+    }
+
+    pub fn insert_plugin(
+        &mut self,
+        index: usize,
+        name: &str,
+        configuration: Option<serde_json::Map<String, serde_json::Value>>,
+    ) -> Result<(), Error> {
+        self.manifest.insert_plugin(index, name, configuration)?;
+        self.mark(ModifiedFlag::Modified);
+        Ok(())
+    }
+
+    pub fn remove_plugin(&mut self, name: &str) -> Result<(), Error> {
+        self.manifest.remove_plugin(name)?;
+        self.mark(ModifiedFlag::Modified);
+        Ok(())
+    }
+
+    pub fn move_plugin(&mut self, name: &str, index: usize) -> Result<(), Error> {
+        let modified = self.manifest.move_plugin(name, index)?;
+        self.mark(modified);
+        Ok(())
+    }
+
+    pub fn set_plugin_options(
+        &mut self,
+        name: &str,
+        options: serde_json::Map<String, serde_json::Value>,
+    ) -> Result<(), Error> {
+        let modified = self.manifest.set_plugin_options(name, options)?;
+        self.mark(modified);
+        Ok(())
+    }
+
+    pub fn generate_assets_in(
+        &mut self,
+        plugin: AssetPlugin,
+        template: &AssetTemplate,
+        binaries: &[&str],
+        targets: &[&str],
+    ) -> Result<(), Error> {
+        let modified = self
+            .manifest
+            .generate_assets_in(plugin, template, binaries, targets)?;
+        self.mark(modified);
+        Ok(())
+    }
+}
+
+/// Create a new, uniquely named file in the same directory as `path`, so it
+/// can later be renamed over `path` atomically.
+fn create_temporary_file(path: &Path) -> Result<(PathBuf, File), Error> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut attempt = 0;
+    loop {
+        let temporary_path = path.with_file_name(format!(
+            ".{}.{}.{}.tmp",
+            file_name,
+            std::process::id(),
+            attempt
+        ));
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&temporary_path)
+        {
+            Ok(file) => return Ok((temporary_path, file)),
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists && attempt < 100 => attempt += 1,
+            Err(err) => return Err(Error::file_open_error(err, &temporary_path)),
+        }
+    }
 }
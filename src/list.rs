@@ -0,0 +1,69 @@
+use std::io::{self, Write};
+
+use configure_semantic_release_assets::Asset;
+
+use crate::cli::ListFormat;
+
+/// Placeholder for a missing label or name in text output.
+const MISSING: &str = "-";
+
+pub(crate) fn print_assets(
+    mut w: impl Write,
+    assets: &[Asset],
+    format: ListFormat,
+) -> io::Result<()> {
+    match format {
+        ListFormat::Text => {
+            let label_width = assets
+                .iter()
+                .map(|asset| asset.label.unwrap_or(MISSING).len())
+                .max()
+                .unwrap_or_default();
+            let path_width = assets
+                .iter()
+                .map(|asset| asset.path.join(" ").len())
+                .max()
+                .unwrap_or_default();
+            for asset in assets {
+                writeln!(
+                    w,
+                    "{:label_width$}  {:path_width$}  {}",
+                    asset.label.unwrap_or(MISSING),
+                    asset.path.join(" "),
+                    asset.name.unwrap_or(MISSING),
+                )?;
+            }
+        }
+        ListFormat::Json => {
+            let assets: Vec<_> = assets
+                .iter()
+                .map(|asset| {
+                    let path = match asset.path.as_slice() {
+                        [path] => serde_json::Value::from(*path),
+                        paths => serde_json::Value::from(paths.to_vec()),
+                    };
+                    serde_json::json!({
+                        "label": asset.label,
+                        "path": path,
+                        "name": asset.name,
+                    })
+                })
+                .collect();
+            serde_json::to_writer_pretty(&mut w, &assets)?;
+            writeln!(w)?;
+        }
+        ListFormat::Tsv => {
+            for asset in assets {
+                writeln!(
+                    w,
+                    "{}\t{}\t{}",
+                    asset.label.unwrap_or_default(),
+                    asset.path.join(","),
+                    asset.name.unwrap_or_default(),
+                )?;
+            }
+        }
+    }
+
+    w.flush()
+}
@@ -0,0 +1,675 @@
+//! A span-tracking parser and printer for JSON documents and JavaScript
+//! object literals.
+//!
+//! Every JSON document is also a JavaScript literal, so one parser serves
+//! both. Recording where each value lives in the source lets edits be applied
+//! as text patches that leave untouched regions byte-identical.
+
+use std::ops::Range;
+
+#[derive(Debug)]
+pub enum ParseError {
+    /// Could not find `module.exports =` or `export default`
+    MissingExport,
+
+    /// The exported value is not a static literal
+    DynamicExport { offset: usize },
+
+    /// Malformed JavaScript literal
+    Syntax {
+        offset: usize,
+        expected: &'static str,
+    },
+}
+
+impl std::error::Error for ParseError {}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::MissingExport => {
+                write!(f, "expected `module.exports =` or `export default`")
+            }
+            ParseError::DynamicExport { offset } => {
+                write!(
+                    f,
+                    "exported value is not a static literal at offset {offset}"
+                )
+            }
+            ParseError::Syntax { offset, expected } => {
+                write!(f, "expected {expected} at offset {offset}")
+            }
+        }
+    }
+}
+
+/// A parsed value and the byte range of its source text.
+#[derive(Debug)]
+pub(crate) struct Node {
+    pub span: Range<usize>,
+    pub kind: NodeKind,
+}
+
+#[derive(Debug)]
+pub(crate) enum NodeKind {
+    Scalar(serde_json::Value),
+    Array(Vec<Node>),
+    Object(Vec<Member>),
+}
+
+#[derive(Debug)]
+pub(crate) struct Member {
+    pub key: String,
+    /// From the start of the key to the end of the value
+    pub span: Range<usize>,
+    pub value: Node,
+}
+
+impl Node {
+    pub(crate) fn to_value(&self) -> serde_json::Value {
+        match &self.kind {
+            NodeKind::Scalar(value) => value.clone(),
+            NodeKind::Array(elements) => elements.iter().map(Node::to_value).collect(),
+            NodeKind::Object(members) => serde_json::Value::Object(
+                members
+                    .iter()
+                    .map(|member| (member.key.clone(), member.value.to_value()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Whether this node represents `value`, without building a copy of it.
+    pub(crate) fn matches(&self, value: &serde_json::Value) -> bool {
+        match (&self.kind, value) {
+            (NodeKind::Scalar(scalar), value) => scalar == value,
+            (NodeKind::Array(elements), serde_json::Value::Array(values)) => {
+                elements.len() == values.len()
+                    && elements
+                        .iter()
+                        .zip(values)
+                        .all(|(element, value)| element.matches(value))
+            }
+            (NodeKind::Object(members), serde_json::Value::Object(values)) => {
+                members.len() == values.len()
+                    && members.iter().zip(values).all(|(member, (key, value))| {
+                        &member.key == key && member.value.matches(value)
+                    })
+            }
+            _ => false,
+        }
+    }
+}
+
+pub(crate) fn is_identifier_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || c == '$'
+}
+
+pub(crate) fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Parse a document consisting of a single JSON value.
+pub(crate) fn parse_document(source: &str) -> Result<Node, ParseError> {
+    let mut parser = Parser::new(source, 0);
+    let node = parser.parse_value()?;
+    parser.skip_trivia();
+    match parser.peek() {
+        None => Ok(node),
+        Some(_) => Err(parser.syntax_error("end of document")),
+    }
+}
+
+pub(crate) struct Parser<'a> {
+    source: &'a str,
+    pub position: usize,
+    /// Quote character of the first string literal
+    pub quote: Option<char>,
+    /// Whether the first object key was a string literal
+    pub quoted_keys: Option<bool>,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(source: &'a str, position: usize) -> Self {
+        Self {
+            source,
+            position,
+            quote: None,
+            quoted_keys: None,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub(crate) fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn syntax_error(&self, expected: &'static str) -> ParseError {
+        ParseError::Syntax {
+            offset: self.position,
+            expected,
+        }
+    }
+
+    fn dynamic_error(&self) -> ParseError {
+        ParseError::DynamicExport {
+            offset: self.position,
+        }
+    }
+
+    pub(crate) fn skip_trivia(&mut self) {
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("//") {
+                let end = rest.find('\n').unwrap_or(rest.len());
+                self.position += end;
+            } else if let Some(comment) = rest.strip_prefix("/*") {
+                let end = comment.find("*/").map_or(rest.len(), |end| end + 4);
+                self.position += end;
+            } else if self.peek().is_some_and(char::is_whitespace) {
+                self.bump();
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// Consume `token` followed by trivia, if present.
+    pub(crate) fn eat_token(&mut self, token: &str) -> bool {
+        self.skip_trivia();
+        if !self.rest().starts_with(token) {
+            return false;
+        }
+        let after = self.rest()[token.len()..].chars().next();
+        let is_word = token.chars().all(is_identifier_char);
+        if is_word && after.is_some_and(is_identifier_char) {
+            return false;
+        }
+        // `=` must not be the start of `==` or `=>`
+        if token == "=" && matches!(after, Some('=') | Some('>')) {
+            return false;
+        }
+        self.position += token.len();
+        true
+    }
+
+    pub(crate) fn identifier(&mut self) -> &'a str {
+        let start = self.position;
+        while self.peek().is_some_and(is_identifier_char) {
+            self.bump();
+        }
+        &self.source[start..self.position]
+    }
+
+    pub(crate) fn skip_string(&mut self, quote: char) {
+        self.bump();
+        while let Some(c) = self.bump() {
+            match c {
+                '\\' => {
+                    self.bump();
+                }
+                c if c == quote => return,
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn parse_value(&mut self) -> Result<Node, ParseError> {
+        self.skip_trivia();
+        let start = self.position;
+        let kind = match self.peek() {
+            Some('{') => NodeKind::Object(self.parse_object()?),
+            Some('[') => NodeKind::Array(self.parse_array()?),
+            Some(c @ ('"' | '\'' | '`')) => {
+                NodeKind::Scalar(serde_json::Value::String(self.parse_string(c)?))
+            }
+            Some(c) if c == '-' || c == '.' || c.is_ascii_digit() => {
+                NodeKind::Scalar(self.parse_number()?)
+            }
+            Some(c) if is_identifier_start(c) => match self.identifier() {
+                "true" => NodeKind::Scalar(serde_json::Value::Bool(true)),
+                "false" => NodeKind::Scalar(serde_json::Value::Bool(false)),
+                "null" => NodeKind::Scalar(serde_json::Value::Null),
+                _ => return Err(ParseError::DynamicExport { offset: start }),
+            },
+            Some(_) => return Err(self.dynamic_error()),
+            None => return Err(self.syntax_error("a value")),
+        };
+        Ok(Node {
+            span: start..self.position,
+            kind,
+        })
+    }
+
+    fn parse_object(&mut self) -> Result<Vec<Member>, ParseError> {
+        let mut members = Vec::new();
+        self.bump();
+        loop {
+            self.skip_trivia();
+            if self.peek() == Some('}') {
+                self.bump();
+                return Ok(members);
+            }
+
+            let start = self.position;
+            let key = match self.peek() {
+                Some(c @ ('"' | '\'')) => {
+                    self.quoted_keys.get_or_insert(true);
+                    self.parse_string(c)?
+                }
+                Some(c) if is_identifier_start(c) => {
+                    self.quoted_keys.get_or_insert(false);
+                    self.identifier().to_owned()
+                }
+                Some(c) if c.is_ascii_digit() => match self.parse_number()? {
+                    serde_json::Value::Number(number) => number.to_string(),
+                    _ => unreachable!("parse_number returns numbers"),
+                },
+                // Spreads, computed keys and anything else require evaluation
+                Some(_) => return Err(self.dynamic_error()),
+                None => return Err(self.syntax_error("`}`")),
+            };
+
+            self.skip_trivia();
+            match self.peek() {
+                Some(':') => {
+                    self.bump();
+                }
+                // Shorthand properties and methods reference runtime values
+                Some(',') | Some('}') | Some('(') => return Err(self.dynamic_error()),
+                _ => return Err(self.syntax_error("`:`")),
+            }
+
+            let value = self.parse_value()?;
+            members.push(Member {
+                key,
+                span: start..value.span.end,
+                value,
+            });
+
+            self.skip_trivia();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some('}') => {}
+                _ => return Err(self.syntax_error("`,` or `}`")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Vec<Node>, ParseError> {
+        let mut elements = Vec::new();
+        self.bump();
+        loop {
+            self.skip_trivia();
+            if self.peek() == Some(']') {
+                self.bump();
+                return Ok(elements);
+            }
+
+            elements.push(self.parse_value()?);
+
+            self.skip_trivia();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some(']') => {}
+                _ => return Err(self.syntax_error("`,` or `]`")),
+            }
+        }
+    }
+
+    fn parse_string(&mut self, quote: char) -> Result<String, ParseError> {
+        if quote != '`' {
+            self.quote.get_or_insert(quote);
+        }
+        let mut string = String::new();
+        self.bump();
+        loop {
+            match self.bump() {
+                None => return Err(self.syntax_error("end of string")),
+                Some(c) if c == quote => return Ok(string),
+                Some('$') if quote == '`' && self.peek() == Some('{') => {
+                    return Err(ParseError::DynamicExport {
+                        offset: self.position - 1,
+                    })
+                }
+                Some('\\') => self.parse_escape(&mut string)?,
+                Some('\n') if quote != '`' => return Err(self.syntax_error("end of string")),
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    fn parse_escape(&mut self, string: &mut String) -> Result<(), ParseError> {
+        let escaped = match self.bump() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('v') => '\u{b}',
+            Some('0') => '\0',
+            // Line continuation
+            Some('\n') => return Ok(()),
+            Some('x') => self.parse_code_point(2)?,
+            Some('u') if self.peek() == Some('{') => {
+                self.bump();
+                let start = self.position;
+                while self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+                    self.bump();
+                }
+                let code_point = u32::from_str_radix(&self.source[start..self.position], 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.syntax_error("a unicode escape"))?;
+                if self.bump() != Some('}') {
+                    return Err(self.syntax_error("`}`"));
+                }
+                code_point
+            }
+            Some('u') => self.parse_utf16_escape()?,
+            Some(c) => c,
+            None => return Err(self.syntax_error("an escape sequence")),
+        };
+        string.push(escaped);
+        Ok(())
+    }
+
+    /// A `\uXXXX` escape, which may be the first half of a surrogate pair.
+    fn parse_utf16_escape(&mut self) -> Result<char, ParseError> {
+        let high = self.parse_hex(4)?;
+        if !(0xD800..0xDC00).contains(&high) || !self.rest().starts_with("\\u") {
+            return char::from_u32(high).ok_or_else(|| self.syntax_error("a unicode escape"));
+        }
+        self.position += 2;
+        let low = self.parse_hex(4)?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(self.syntax_error("a low surrogate"));
+        }
+        let code_point = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+        char::from_u32(code_point).ok_or_else(|| self.syntax_error("a unicode escape"))
+    }
+
+    fn parse_code_point(&mut self, digits: usize) -> Result<char, ParseError> {
+        let code_point = self.parse_hex(digits)?;
+        char::from_u32(code_point).ok_or_else(|| self.syntax_error("a hexadecimal escape"))
+    }
+
+    fn parse_hex(&mut self, digits: usize) -> Result<u32, ParseError> {
+        let value = self
+            .rest()
+            .get(..digits)
+            .filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| self.syntax_error("a hexadecimal escape"))?;
+        self.position += digits;
+        Ok(value)
+    }
+
+    fn parse_number(&mut self) -> Result<serde_json::Value, ParseError> {
+        let start = self.position;
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_')
+        {
+            let c = self.bump();
+            // Exponent sign
+            if matches!(c, Some('e') | Some('E')) && matches!(self.peek(), Some('+') | Some('-')) {
+                self.bump();
+            }
+        }
+
+        let literal = self.source[start..self.position].replace('_', "");
+        let number = if let Ok(integer) = literal.parse::<i64>() {
+            serde_json::Number::from(integer)
+        } else if let Ok(integer) = literal.parse::<u64>() {
+            serde_json::Number::from(integer)
+        } else {
+            literal
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .ok_or(ParseError::Syntax {
+                    offset: start,
+                    expected: "a number",
+                })?
+        };
+        Ok(serde_json::Value::Number(number))
+    }
+}
+
+/// How to print values that have no original text to preserve.
+#[derive(Clone, Debug)]
+pub(crate) struct Style {
+    pub quote: char,
+    /// Quote object keys even when they are valid identifiers
+    pub quote_keys: bool,
+    /// One level of indentation
+    pub indent: String,
+}
+
+impl Style {
+    pub(crate) fn json(source: &str) -> Self {
+        Self {
+            quote: '"',
+            quote_keys: true,
+            indent: detect_indent(source),
+        }
+    }
+
+    /// Print `value` as if it began on a line indented by `base_indent`.
+    pub(crate) fn print(&self, value: &serde_json::Value, base_indent: &str) -> String {
+        let mut out = String::new();
+        self.print_value(&mut out, value, 0);
+        out.replace('\n', &format!("\n{base_indent}"))
+    }
+
+    fn print_value(&self, out: &mut String, value: &serde_json::Value, depth: usize) {
+        match value {
+            serde_json::Value::Null => out.push_str("null"),
+            serde_json::Value::Bool(boolean) => out.push_str(&boolean.to_string()),
+            serde_json::Value::Number(number) => out.push_str(&number.to_string()),
+            serde_json::Value::String(string) => self.print_string(out, string),
+            serde_json::Value::Array(array) => {
+                if array.is_empty() {
+                    out.push_str("[]");
+                    return;
+                }
+                out.push_str("[\n");
+                for (index, element) in array.iter().enumerate() {
+                    out.push_str(&self.indent.repeat(depth + 1));
+                    self.print_value(out, element, depth + 1);
+                    if index + 1 < array.len() {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                out.push_str(&self.indent.repeat(depth));
+                out.push(']');
+            }
+            serde_json::Value::Object(object) => {
+                if object.is_empty() {
+                    out.push_str("{}");
+                    return;
+                }
+                out.push_str("{\n");
+                for (index, (key, value)) in object.iter().enumerate() {
+                    out.push_str(&self.indent.repeat(depth + 1));
+                    out.push_str(&self.print_key(key));
+                    out.push_str(": ");
+                    self.print_value(out, value, depth + 1);
+                    if index + 1 < object.len() {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                out.push_str(&self.indent.repeat(depth));
+                out.push('}');
+            }
+        }
+    }
+
+    pub(crate) fn print_key(&self, key: &str) -> String {
+        let mut chars = key.chars();
+        let is_identifier =
+            chars.next().is_some_and(is_identifier_start) && chars.all(is_identifier_char);
+        if is_identifier && !self.quote_keys {
+            key.to_owned()
+        } else {
+            let mut out = String::new();
+            self.print_string(&mut out, key);
+            out
+        }
+    }
+
+    fn print_string(&self, out: &mut String, string: &str) {
+        out.push(self.quote);
+        for c in string.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c == self.quote => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                // Valid in both JSON and JavaScript
+                c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push(self.quote);
+    }
+}
+
+/// The first indentation found in `source`, or two spaces.
+pub(crate) fn detect_indent(source: &str) -> String {
+    source
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let content = line.trim_start_matches([' ', '\t']);
+            &line[..line.len() - content.len()]
+        })
+        .find(|indent| !indent.is_empty())
+        .map(|indent| match indent.starts_with('\t') {
+            true => "\t".to_owned(),
+            false => indent.to_owned(),
+        })
+        .unwrap_or_else(|| "  ".to_owned())
+}
+
+/// The whitespace at the start of the line containing `position`.
+pub(crate) fn line_indent(source: &str, position: usize) -> &str {
+    let line_start = source[..position]
+        .rfind('\n')
+        .map_or(0, |newline| newline + 1);
+    let line = &source[line_start..];
+    let content = line.trim_start_matches([' ', '\t']);
+    &line[..line.len() - content.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn parse(source: &str) -> serde_json::Value {
+        parse_document(source).unwrap().to_value()
+    }
+
+    fn syntax_error(source: &str) -> &'static str {
+        match parse_document(source) {
+            Err(ParseError::Syntax { expected, .. }) => expected,
+            other => panic!("expected a syntax error for {:?}, got {:?}", source, other),
+        }
+    }
+
+    #[test]
+    fn should_decode_escapes() {
+        assert_eq!(
+            json!("a\nb\tc\r\\\"'\u{8}\u{c}\u{b}\0"),
+            parse(r#""a\nb\tc\r\\\"\'\b\f\v\0""#)
+        );
+        assert_eq!(json!("\u{41}\u{e9}"), parse(r#""\x41\u00e9""#));
+        assert_eq!(json!("\u{1f600}"), parse(r#""\u{1F600}""#));
+        assert_eq!(json!("ab"), parse("'a\\\nb'"));
+    }
+
+    #[test]
+    fn should_combine_surrogate_pairs() {
+        assert_eq!(json!("\u{1f600}"), parse(r#""\ud83d\ude00""#));
+    }
+
+    #[test]
+    fn should_reject_invalid_surrogates() {
+        assert_eq!("a low surrogate", syntax_error(r#""\ud83d\u0041""#));
+        assert_eq!("a unicode escape", syntax_error(r#""\ud83d""#));
+        assert_eq!("a unicode escape", syntax_error(r#""\ude00""#));
+    }
+
+    #[test]
+    fn should_reject_malformed_hexadecimal_escapes() {
+        assert_eq!("a hexadecimal escape", syntax_error(r#""\u+041""#));
+        assert_eq!("a hexadecimal escape", syntax_error(r#""\x4""#));
+    }
+
+    #[test]
+    fn should_parse_numbers() {
+        assert_eq!(
+            json!([
+                0,
+                -12,
+                1_000_000,
+                18446744073709551615u64,
+                0.5,
+                -1.25,
+                1e3,
+                0.5
+            ]),
+            parse("[0, -12, 1_000_000, 18446744073709551615, .5, -1.25, 1e+3, 5E-1]")
+        );
+        assert_eq!("a number", syntax_error("[1.2.3]"));
+        assert_eq!("a number", syntax_error("-"));
+    }
+
+    #[test]
+    fn should_skip_comments() {
+        assert_eq!(
+            json!({"a": [1, 2]}),
+            parse("// leading\n{ /* key */ a: [1, // one\n 2 /* two */], } // trailing\n")
+        );
+        assert_eq!(
+            json!("// not a comment"),
+            parse(r#"/* before */ "// not a comment""#)
+        );
+    }
+
+    #[test]
+    fn should_record_spans() {
+        let source = r#"{ "a": [1, "b"] }"#;
+        let node = parse_document(source).unwrap();
+        let NodeKind::Object(members) = &node.kind else {
+            panic!("expected an object");
+        };
+        assert_eq!(r#""a": [1, "b"]"#, &source[members[0].span.clone()]);
+        assert_eq!(r#"[1, "b"]"#, &source[members[0].value.span.clone()]);
+    }
+}
@@ -2,20 +2,33 @@ use std::{
     collections::HashSet,
     ffi::OsString,
     fmt::Display,
+    io,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 use find_semantic_release_config::find_semantic_release_configuration;
 
-use configure_semantic_release_assets::{SemanticReleaseConfiguration, WriteTo};
+use configure_semantic_release_assets::{
+    find_cargo_binaries, AssetField, AssetPattern, AssetPlugin, AssetTemplate, MatchOptions,
+    ModifiedFlag, OnDuplicateLabel, PatternError, SemanticReleaseConfiguration, WriteTo,
+    BINARY_PLACEHOLDER, DEFAULT_BINARIES_LABEL_TEMPLATE, DEFAULT_LABEL_TEMPLATE,
+};
 
+mod check;
 mod cli;
+mod entry_file;
+mod list;
 mod little_anyhow;
+mod options;
 
 use cli::Cli;
 
-const SUPPORTED_FILE_TYPES: &[&str] = &["json"];
+/// Value of `--input` naming stdin.
+const STDIN: &str = "-";
+
+const SUPPORTED_FILE_TYPES: &[&str] = &["json", "yaml", "yml", "js", "cjs", "mjs"];
 
 #[derive(Debug)]
 #[non_exhaustive]
@@ -42,6 +55,9 @@ impl Display for Error {
                 )?;
                 write!(f, "Currently configure-semantic-release-assets only supports the following extensions: {:?}", SUPPORTED_FILE_TYPES)
             }
+            ErrorKind::InvalidPattern(_) => write!(f, "unable to parse asset pattern"),
+            ErrorKind::InvalidPluginOption(_) => write!(f, "unable to parse plugin option"),
+            ErrorKind::InvalidEntryFile(_) => write!(f, "unable to read whitelist file"),
         }
     }
 }
@@ -52,6 +68,9 @@ impl std::error::Error for Error {
             ErrorKind::FindConfiguration(err) => Some(err),
             ErrorKind::MissingConfiguration { directory: _ } => None,
             ErrorKind::UnsupportedFileExtension { extension: _ } => None,
+            ErrorKind::InvalidPattern(err) => Some(err),
+            ErrorKind::InvalidPluginOption(err) => Some(err),
+            ErrorKind::InvalidEntryFile(err) => Some(err),
         }
     }
 }
@@ -64,6 +83,12 @@ enum ErrorKind {
     MissingConfiguration { directory: PathBuf },
     #[non_exhaustive]
     UnsupportedFileExtension { extension: Option<OsString> },
+    #[non_exhaustive]
+    InvalidPattern(PatternError),
+    #[non_exhaustive]
+    InvalidPluginOption(options::OptionError),
+    #[non_exhaustive]
+    InvalidEntryFile(entry_file::EntryFileError),
 }
 
 impl From<ErrorKind> for little_anyhow::Error {
@@ -74,11 +99,11 @@ impl From<ErrorKind> for little_anyhow::Error {
 }
 
 fn find_semantic_release_config(directory: &Path) -> Result<PathBuf, ErrorKind> {
-    Ok(find_semantic_release_configuration(&directory)
+    find_semantic_release_configuration(directory)
         .map_err(ErrorKind::FindConfiguration)?
         .ok_or_else(|| ErrorKind::MissingConfiguration {
             directory: directory.to_owned(),
-        })?)
+        })
 }
 
 fn is_unsupported_file_extension(config: &Path) -> bool {
@@ -93,40 +118,200 @@ fn is_unsupported_file_extension(config: &Path) -> bool {
     }
 }
 
+/// Patterns may be passed as separate arguments or as one whitespace-separated argument.
+fn parse_patterns(raw_patterns: Vec<String>, regex: bool) -> Result<Vec<AssetPattern>, ErrorKind> {
+    let patterns: HashSet<String> = raw_patterns
+        .into_iter()
+        .flat_map(|s| {
+            s.split_whitespace()
+                .map(|s| s.to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    patterns
+        .iter()
+        .map(|pattern| match regex {
+            true => AssetPattern::regex(pattern),
+            false => AssetPattern::from_str(pattern),
+        })
+        .collect::<Result<_, _>>()
+        .map_err(ErrorKind::InvalidPattern)
+}
+
 fn main() -> Result<(), little_anyhow::Error> {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
 
     let cli = Cli::parse();
-    let config = find_semantic_release_config(&cli.directory)?;
-
-    if is_unsupported_file_extension(&config) {
-        return Err(ErrorKind::UnsupportedFileExtension {
-            extension: config.extension().map(ToOwned::to_owned),
-        })?;
+    if cli.format.is_some() && cli.input.as_deref() != Some(Path::new(STDIN)) {
+        Cli::command()
+            .error(
+                clap::error::ErrorKind::ArgumentConflict,
+                "--format applies only to a manifest read from stdin with `--input -`",
+            )
+            .exit();
     }
-
-    let mut configuration = SemanticReleaseConfiguration::read_from_file(&config)?;
+    let mut configuration = match (cli.input, cli.format) {
+        (Some(input), Some(format)) if input == Path::new(STDIN) => {
+            SemanticReleaseConfiguration::from_reader(io::stdin().lock(), format.into())?
+        }
+        (input, _) => {
+            let config = match input {
+                Some(input) => input,
+                None => find_semantic_release_config(&cli.directory)?,
+            };
+            if is_unsupported_file_extension(&config) {
+                return Err(ErrorKind::UnsupportedFileExtension {
+                    extension: config.extension().map(ToOwned::to_owned),
+                }
+                .into());
+            }
+            SemanticReleaseConfiguration::read_from_file(&config)?
+        }
+    };
+    let plugin = AssetPlugin::from(cli.plugin);
+    let original_summary = check::describe(configuration.manifest(), plugin);
 
     match cli.subcommand {
         cli::Subcommand::Whitelist {
-            whitelist: raw_whitelist,
+            regex,
+            match_on,
+            from_file,
+            key,
+            whitelist: mut raw_whitelist,
         } => {
-            let whitelist: HashSet<String> = raw_whitelist
+            if let Some(from_file) = from_file {
+                raw_whitelist.extend(
+                    entry_file::read_entries(&from_file, key.as_deref())
+                        .map_err(ErrorKind::InvalidEntryFile)?,
+                );
+            }
+            let options = MatchOptions {
+                plugin,
+                field: match_on.map_or(plugin.default_field(), Into::into),
+            };
+            configuration.apply_whitelist_with(options, parse_patterns(raw_whitelist, regex)?);
+        }
+        cli::Subcommand::Blacklist {
+            regex,
+            match_on,
+            blacklist: raw_blacklist,
+        } => {
+            let options = MatchOptions {
+                plugin,
+                field: match_on.map_or(plugin.default_field(), Into::into),
+            };
+            configuration.apply_blacklist_with(options, parse_patterns(raw_blacklist, regex)?);
+        }
+        cli::Subcommand::Add {
+            path,
+            label,
+            name,
+            update,
+        } => {
+            let on_duplicate = match update {
+                true => OnDuplicateLabel::Update,
+                false => OnDuplicateLabel::Refuse,
+            };
+            configuration.add_asset_in(
+                plugin,
+                &path,
+                label.as_deref(),
+                name.as_deref(),
+                on_duplicate,
+            )?;
+        }
+        cli::Subcommand::Generate {
+            path,
+            label,
+            name,
+            binary,
+            targets,
+        } => {
+            let uses_binary = [Some(&path), label.as_ref(), name.as_ref()]
                 .into_iter()
-                .flat_map(|s| {
-                    s.split_whitespace()
-                        .map(|s| s.to_owned())
-                        .collect::<Vec<_>>()
-                })
-                .collect();
-
-            configuration.apply_whitelist(whitelist);
+                .flatten()
+                .any(|template| template.contains(BINARY_PLACEHOLDER));
+            let binaries = match binary.is_empty() && uses_binary {
+                true => find_cargo_binaries(&cli.directory)?,
+                false => binary,
+            };
+            let label = label.or_else(|| {
+                let template = match binaries.len() > 1 {
+                    true => DEFAULT_BINARIES_LABEL_TEMPLATE,
+                    false => DEFAULT_LABEL_TEMPLATE,
+                };
+                plugin
+                    .supports(AssetField::Label)
+                    .then(|| template.to_owned())
+            });
+            let template = AssetTemplate { path, label, name };
+            let binaries: Vec<&str> = binaries.iter().map(String::as_str).collect();
+            let targets: Vec<&str> = targets.iter().flat_map(|s| s.split_whitespace()).collect();
+            configuration.generate_assets_in(plugin, &template, &binaries, &targets)?;
+        }
+        cli::Subcommand::Plugin { command } => match command {
+            cli::PluginCommand::Add {
+                name,
+                index,
+                options,
+            } => {
+                let configuration_options = match options.is_empty() {
+                    true => None,
+                    false => Some(
+                        options::parse_options(options).map_err(ErrorKind::InvalidPluginOption)?,
+                    ),
+                };
+                configuration.insert_plugin(
+                    index.unwrap_or(usize::MAX),
+                    &name,
+                    configuration_options,
+                )?;
+            }
+            cli::PluginCommand::Remove { name } => configuration.remove_plugin(&name)?,
+            cli::PluginCommand::Move { name, index } => configuration.move_plugin(&name, index)?,
+            cli::PluginCommand::Set { name, options } => configuration.set_plugin_options(
+                &name,
+                options::parse_options(options).map_err(ErrorKind::InvalidPluginOption)?,
+            )?,
+        },
+        cli::Subcommand::List { format } => {
+            list::print_assets(
+                io::stdout(),
+                &configuration.manifest().assets_in(plugin),
+                format,
+            )?;
+            return Ok(());
         }
     }
 
-    match cli.in_place {
-        true => configuration.write_if_modified(WriteTo::InPlace)?,
-        false => configuration.write_if_modified(WriteTo::Stdout)?,
+    if cli.check {
+        let modified = configuration.modified();
+        check::print_summary(
+            io::stdout(),
+            configuration.display_path(),
+            &original_summary,
+            &check::describe(configuration.manifest(), plugin),
+            &modified,
+        )?;
+        if modified == ModifiedFlag::Modified {
+            std::process::exit(check::MODIFIED_EXIT_CODE);
+        }
+        return Ok(());
+    }
+
+    let write_to = match (cli.output, cli.in_place, cli.diff) {
+        (Some(output), _, _) => WriteTo::File(output),
+        (None, true, _) => match cli.backup {
+            Some(suffix) => WriteTo::InPlaceWithBackup { suffix },
+            None => WriteTo::InPlace,
+        },
+        (None, false, true) => WriteTo::Diff,
+        (None, false, false) => WriteTo::Stdout,
+    };
+    match cli.always_write {
+        true => configuration.write_to(write_to)?,
+        false => configuration.write_if_modified(write_to)?,
     };
 
     Ok(())
@@ -0,0 +1,64 @@
+//! Plugin options given on the command line.
+
+use serde_json::{Map, Value};
+
+/// Parse `key=value` pairs and JSON objects into plugin options.
+///
+/// A value that parses as JSON is used as such, so `npmPublish=false` sets a
+/// boolean; any other value is taken as a string.
+pub(crate) fn parse_options(raw_options: Vec<String>) -> Result<Map<String, Value>, OptionError> {
+    let mut options = Map::new();
+    for option in raw_options {
+        if option.trim_start().starts_with('{') {
+            let fragment: Map<String, Value> = serde_json::from_str(&option)
+                .map_err(|source| OptionError::InvalidJson { source, option })?;
+            options.extend(fragment);
+            continue;
+        }
+
+        let Some((key, value)) = option.split_once('=') else {
+            return Err(OptionError::MissingValue { option });
+        };
+        let value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_owned()));
+        options.insert(key.to_owned(), value);
+    }
+    Ok(options)
+}
+
+#[derive(Debug)]
+pub(crate) enum OptionError {
+    /// Option {option} is not a `key=value` pair
+    MissingValue { option: String },
+
+    /// Option {option} is not a valid JSON object
+    InvalidJson {
+        source: serde_json::Error,
+        option: String,
+    },
+}
+
+impl std::error::Error for OptionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OptionError::MissingValue { option: _ } => None,
+            OptionError::InvalidJson { source, option: _ } => Some(source),
+        }
+    }
+}
+
+impl std::fmt::Display for OptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptionError::MissingValue { option } => {
+                write!(
+                    f,
+                    "expected plugin option {:?} to be a key=value pair",
+                    option
+                )
+            }
+            OptionError::InvalidJson { source: _, option } => {
+                write!(f, "invalid JSON object {:?}", option)
+            }
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Apply edits to a JSON or JavaScript literal as minimal text patches.
+//!
+//! Unchanged values keep their original text, including whitespace and
+//! comments. Only values that changed are re-printed, in the [`Style`]
+//! detected from the original source.
+
+use std::ops::Range;
+
+use crate::literal::{line_indent, Node, NodeKind, Parser, Style};
+
+/// Render `source` with the value at `root` replaced by `value`.
+pub(crate) fn patch(source: &str, root: &Node, value: &serde_json::Value, style: &Style) -> String {
+    let patcher = Patcher { source, style };
+    let mut patched = String::with_capacity(source.len());
+    patched.push_str(&source[..root.span.start]);
+    patched.push_str(&patcher.render(root, value));
+    patched.push_str(&source[root.span.end..]);
+    patched
+}
+
+/// One child of an edited array or object.
+enum Piece {
+    /// Derived from the original child at this index
+    Original(usize, String),
+    /// Inserted by the edit
+    Inserted(String),
+}
+
+struct Patcher<'a> {
+    source: &'a str,
+    style: &'a Style,
+}
+
+impl<'a> Patcher<'a> {
+    fn text(&self, span: &Range<usize>) -> &'a str {
+        &self.source[span.clone()]
+    }
+
+    fn render(&self, node: &Node, value: &serde_json::Value) -> String {
+        if node.matches(value) {
+            return self.text(&node.span).to_owned();
+        }
+
+        match (&node.kind, value) {
+            (NodeKind::Array(elements), serde_json::Value::Array(values)) => {
+                self.render_array(node, elements, values)
+            }
+            (NodeKind::Object(members), serde_json::Value::Object(values)) => {
+                self.render_object(node, members, values)
+            }
+            _ => self
+                .style
+                .print(value, line_indent(self.source, node.span.start)),
+        }
+    }
+
+    fn render_array(&self, node: &Node, elements: &[Node], values: &[serde_json::Value]) -> String {
+        let spans: Vec<_> = elements
+            .iter()
+            .map(|element| element.span.clone())
+            .collect();
+        let child_indent = self.child_indent(node, &spans);
+
+        let mut pieces = Vec::with_capacity(values.len());
+        let mut deleted = Vec::new();
+        let mut inserted = Vec::new();
+        let flush = |deleted: &mut Vec<usize>, inserted: &mut Vec<usize>, pieces: &mut Vec<_>| {
+            // Pair removals with insertions so a modified element is patched
+            // rather than re-printed
+            for (position, &value_index) in inserted.iter().enumerate() {
+                pieces.push(match deleted.get(position) {
+                    Some(&element_index) => Piece::Original(
+                        element_index,
+                        self.render(&elements[element_index], &values[value_index]),
+                    ),
+                    None => Piece::Inserted(self.style.print(&values[value_index], &child_indent)),
+                });
+            }
+            deleted.clear();
+            inserted.clear();
+        };
+
+        for operation in align(elements, values) {
+            match operation {
+                Alignment::Keep(element_index) => {
+                    flush(&mut deleted, &mut inserted, &mut pieces);
+                    pieces.push(Piece::Original(
+                        element_index,
+                        self.text(&elements[element_index].span).to_owned(),
+                    ));
+                }
+                Alignment::Delete(element_index) => deleted.push(element_index),
+                Alignment::Insert(value_index) => inserted.push(value_index),
+            }
+        }
+        flush(&mut deleted, &mut inserted, &mut pieces);
+
+        self.assemble(node, &spans, pieces, &child_indent)
+    }
+
+    fn render_object(
+        &self,
+        node: &Node,
+        members: &[crate::literal::Member],
+        values: &serde_json::Map<String, serde_json::Value>,
+    ) -> String {
+        let spans: Vec<_> = members.iter().map(|member| member.span.clone()).collect();
+        let child_indent = self.child_indent(node, &spans);
+
+        let original_indices: Vec<_> = values
+            .keys()
+            .map(|key| members.iter().position(|member| &member.key == key))
+            .collect();
+        let retained: Vec<_> = original_indices.iter().flatten().collect();
+        if retained.windows(2).any(|pair| pair[0] >= pair[1]) {
+            // Members were reordered, so there is no sensible patch
+            return self.style.print(
+                &serde_json::Value::Object(values.clone()),
+                line_indent(self.source, node.span.start),
+            );
+        }
+
+        let pieces = values
+            .iter()
+            .zip(original_indices)
+            .map(|((key, value), original_index)| match original_index {
+                Some(index) => {
+                    let member = &members[index];
+                    let key_text = &self.source[member.span.start..member.value.span.start];
+                    Piece::Original(
+                        index,
+                        format!("{}{}", key_text, self.render(&member.value, value)),
+                    )
+                }
+                None => Piece::Inserted(format!(
+                    "{}: {}",
+                    self.style.print_key(key),
+                    self.style.print(value, &child_indent)
+                )),
+            })
+            .collect();
+
+        self.assemble(node, &spans, pieces, &child_indent)
+    }
+
+    /// Indentation of the children of `node`, from the first original child
+    /// when it starts its own line.
+    fn child_indent(&self, node: &Node, children: &[Range<usize>]) -> String {
+        match children.first() {
+            Some(first) if self.source[node.span.start..first.start].contains('\n') => {
+                line_indent(self.source, first.start).to_owned()
+            }
+            _ => format!(
+                "{}{}",
+                line_indent(self.source, node.span.start),
+                self.style.indent
+            ),
+        }
+    }
+
+    /// Join `pieces` between the delimiters of `node`. Each original child
+    /// keeps the text around it, such as a comment that follows it on the same
+    /// line; inserted children get a fresh separator.
+    fn assemble(
+        &self,
+        node: &Node,
+        children: &[Range<usize>],
+        pieces: Vec<Piece>,
+        child_indent: &str,
+    ) -> String {
+        let open = node.span.start..node.span.start + 1;
+        let close = node.span.end - 1..node.span.end;
+
+        if pieces.is_empty() {
+            return format!("{}{}", self.text(&open), self.text(&close));
+        }
+
+        // heads[i] precedes child i and tails[i] follows it, up to the closing
+        // text after the last child
+        let mut heads = Vec::with_capacity(children.len());
+        let mut tails = Vec::with_capacity(children.len());
+        let closing = match (children.first(), children.last()) {
+            (Some(first), Some(last)) => {
+                heads.push(self.text(&(open.end..first.start)));
+                for pair in children.windows(2) {
+                    let (tail, head) = split_separator(self.text(&(pair[0].end..pair[1].start)));
+                    tails.push(tail);
+                    heads.push(head);
+                }
+                let (tail, closing) = split_separator(self.text(&(last.end..close.start)));
+                tails.push(tail);
+                closing.to_owned()
+            }
+            _ => format!("\n{}", line_indent(self.source, node.span.start)),
+        };
+        let leading = match heads.first() {
+            Some(leading) => leading.to_string(),
+            None => format!("\n{child_indent}"),
+        };
+        let fresh_head = match leading.contains('\n') {
+            true => format!("\n{child_indent}"),
+            false => " ".to_owned(),
+        };
+        let trailing_comma = tails.last().is_some_and(|tail| comma(tail).is_some());
+
+        let mut assembled = String::new();
+        assembled.push_str(self.text(&open));
+        for (position, piece) in pieces.iter().enumerate() {
+            let is_last = position + 1 == pieces.len();
+            let (head, text, tail) = match piece {
+                Piece::Original(index, text) => (heads[*index], text, tails[*index]),
+                Piece::Inserted(text) => (fresh_head.as_str(), text, ""),
+            };
+            assembled.push_str(match position {
+                0 => &leading,
+                _ => match piece {
+                    Piece::Original(index, _) if *index > 0 => head,
+                    _ => &fresh_head,
+                },
+            });
+            assembled.push_str(text);
+            match (comma(tail), is_last && !trailing_comma) {
+                (Some(comma), true) => {
+                    assembled.push_str(&tail[..comma]);
+                    assembled.push_str(&tail[comma + 1..]);
+                }
+                (None, false) => {
+                    assembled.push(',');
+                    assembled.push_str(tail);
+                }
+                _ => assembled.push_str(tail),
+            }
+        }
+        assembled.push_str(&closing);
+        assembled.push_str(self.text(&close));
+        assembled
+    }
+}
+
+/// Split the text after a child into the part that belongs to the child, its
+/// comma and anything else on the same line, and the part that leads up to
+/// whatever comes next.
+fn split_separator(text: &str) -> (&str, &str) {
+    let after_comma = comma(text).map_or(0, |comma| comma + 1);
+    let split = match text[after_comma..].find('\n') {
+        Some(newline) => after_comma + newline,
+        None => after_comma,
+    };
+    text.split_at(split)
+}
+
+/// Offset of the comma in the text between two children, skipping comments.
+fn comma(text: &str) -> Option<usize> {
+    let mut parser = Parser::new(text, 0);
+    parser.skip_trivia();
+    (parser.peek() == Some(',')).then_some(parser.position)
+}
+
+enum Alignment {
+    Keep(usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Align original elements with edited values by their longest common subsequence.
+fn align(elements: &[Node], values: &[serde_json::Value]) -> Vec<Alignment> {
+    let (n, m) = (elements.len(), values.len());
+    // common[i][j] is the length of the LCS of elements[i..] and values[j..]
+    let mut common = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            common[i][j] = match elements[i].matches(&values[j]) {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let mut alignment = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if elements[i].matches(&values[j]) {
+            alignment.push(Alignment::Keep(i));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            alignment.push(Alignment::Delete(i));
+            i += 1;
+        } else {
+            alignment.push(Alignment::Insert(j));
+            j += 1;
+        }
+    }
+    alignment.extend((i..n).map(Alignment::Delete));
+    alignment.extend((j..m).map(Alignment::Insert));
+    alignment
+}
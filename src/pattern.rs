@@ -0,0 +1,108 @@
+use std::{collections::HashSet, str::FromStr};
+
+/// Prefix marking a whitelist or blacklist entry as a regular expression.
+pub const REGEX_PREFIX: &str = "regex:";
+
+/// Decides whether a release asset is selected by a whitelist or blacklist.
+pub trait AssetMatcher {
+    fn is_match(&self, value: &str) -> bool;
+}
+
+/// Exact matching, one label per entry.
+impl AssetMatcher for HashSet<String> {
+    fn is_match(&self, value: &str) -> bool {
+        self.contains(value)
+    }
+}
+
+/// Matches if any pattern matches.
+impl AssetMatcher for Vec<AssetPattern> {
+    fn is_match(&self, value: &str) -> bool {
+        self.iter().any(|pattern| pattern.is_match(value))
+    }
+}
+
+/// A pattern matched against an entire asset label.
+#[derive(Clone, Debug)]
+pub enum AssetPattern {
+    /// A shell-style glob, such as `*-unknown-linux-*`
+    Glob(glob::Pattern),
+    /// A regular expression, anchored to match the whole value
+    Regex(regex::Regex),
+}
+
+impl AssetPattern {
+    pub fn glob(pattern: &str) -> Result<Self, PatternError> {
+        glob::Pattern::new(pattern)
+            .map(AssetPattern::Glob)
+            .map_err(|source| PatternError::Glob {
+                source,
+                pattern: pattern.to_owned(),
+            })
+    }
+
+    pub fn regex(pattern: &str) -> Result<Self, PatternError> {
+        regex::Regex::new(&format!("^(?:{pattern})$"))
+            .map(AssetPattern::Regex)
+            .map_err(|source| PatternError::Regex {
+                source,
+                pattern: pattern.to_owned(),
+            })
+    }
+
+    pub fn is_match(&self, value: &str) -> bool {
+        match self {
+            AssetPattern::Glob(glob) => glob.matches(value),
+            AssetPattern::Regex(regex) => regex.is_match(value),
+        }
+    }
+}
+
+/// Parse a glob, or a regular expression when prefixed with [`REGEX_PREFIX`].
+impl FromStr for AssetPattern {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix(REGEX_PREFIX) {
+            Some(regex) => AssetPattern::regex(regex),
+            None => AssetPattern::glob(s),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PatternError {
+    /// Invalid glob pattern {pattern}
+    Glob {
+        source: glob::PatternError,
+        pattern: String,
+    },
+
+    /// Invalid regular expression {pattern}
+    Regex {
+        source: regex::Error,
+        pattern: String,
+    },
+}
+
+impl std::error::Error for PatternError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PatternError::Glob { source, pattern: _ } => Some(source),
+            PatternError::Regex { source, pattern: _ } => Some(source),
+        }
+    }
+}
+
+impl std::fmt::Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternError::Glob { source: _, pattern } => {
+                write!(f, "invalid glob pattern {:?}", pattern)
+            }
+            PatternError::Regex { source: _, pattern } => {
+                write!(f, "invalid regular expression {:?}", pattern)
+            }
+        }
+    }
+}
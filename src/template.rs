@@ -0,0 +1,69 @@
+//! Expand asset templates over Rust target triples.
+
+use crate::error::Error;
+
+/// Placeholder replaced by a Rust target triple.
+pub const TARGET_PLACEHOLDER: &str = "{target}";
+
+/// Placeholder replaced by the name of a binary.
+pub const BINARY_PLACEHOLDER: &str = "{binary}";
+
+/// Where semantic-release-action/rust places the binary built for each target.
+pub const DEFAULT_PATH_TEMPLATE: &str =
+    ".semantic-release-action_rust/dist/{target}/{binary}-{target}";
+
+/// Label identifying each generated release asset.
+pub const DEFAULT_LABEL_TEMPLATE: &str = "{target}";
+
+/// Label identifying each generated release asset when several binaries
+/// are released for each target.
+pub const DEFAULT_BINARIES_LABEL_TEMPLATE: &str = "{binary}-{target}";
+
+/// Templates of the properties of a generated asset.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetTemplate {
+    pub path: String,
+    pub label: Option<String>,
+    pub name: Option<String>,
+}
+
+impl Default for AssetTemplate {
+    fn default() -> Self {
+        Self {
+            path: DEFAULT_PATH_TEMPLATE.to_owned(),
+            label: Some(DEFAULT_LABEL_TEMPLATE.to_owned()),
+            name: None,
+        }
+    }
+}
+
+/// The properties of an asset with every placeholder replaced.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ExpandedAsset {
+    pub path: String,
+    pub label: Option<String>,
+    pub name: Option<String>,
+}
+
+impl AssetTemplate {
+    pub(crate) fn expand(
+        &self,
+        binary: Option<&str>,
+        target: &str,
+    ) -> Result<ExpandedAsset, Error> {
+        let expand = |template: &str| {
+            let expanded = template.replace(TARGET_PLACEHOLDER, target);
+            match (binary, expanded.contains(BINARY_PLACEHOLDER)) {
+                (_, false) => Ok(expanded),
+                (Some(binary), true) => Ok(expanded.replace(BINARY_PLACEHOLDER, binary)),
+                (None, true) => Err(Error::missing_template_value_error(BINARY_PLACEHOLDER)),
+            }
+        };
+
+        Ok(ExpandedAsset {
+            path: expand(&self.path)?,
+            label: self.label.as_deref().map(expand).transpose()?,
+            name: self.name.as_deref().map(expand).transpose()?,
+        })
+    }
+}
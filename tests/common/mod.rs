@@ -0,0 +1,63 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+/// An empty directory for `test_name`, removing whatever a previous run left
+/// in it. Directories are unique to each test binary, so tests in different
+/// files may share a name.
+pub fn temp_dir(test_name: &str) -> PathBuf {
+    let directory = std::env::temp_dir()
+        .join(env!("CARGO_PKG_NAME"))
+        .join(env!("CARGO_CRATE_NAME"))
+        .join(test_name);
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+/// A directory for `test_name` holding `files`, given as paths relative to
+/// the directory and their contents.
+pub fn project_in_temp_dir(test_name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let directory = temp_dir(test_name);
+    for (path, contents) in files {
+        let path = directory.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+    directory
+}
+
+/// The path of `file_name`, written with `contents` to a directory for
+/// `test_name`.
+pub fn file_in_temp_dir(test_name: &str, file_name: &str, contents: &str) -> PathBuf {
+    project_in_temp_dir(test_name, &[(file_name, contents)]).join(file_name)
+}
+
+/// The command-line tool under test.
+pub fn binary() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_configure-semantic-release-assets"))
+}
+
+/// Run the command-line tool with `args`, writing `stdin` to its standard
+/// input.
+pub fn run_with_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = binary()
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // The tool may exit before reading its input, such as when it rejects
+    // its arguments
+    let written = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    if let Err(err) = written {
+        assert_eq!(ErrorKind::BrokenPipe, err.kind());
+    }
+    child.wait_with_output().unwrap()
+}
@@ -0,0 +1,200 @@
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{ModifiedFlag, OnDuplicateLabel, SemanticReleaseManifest};
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-gnu/BINARY_NAME-x86_64-unknown-linux-gnu",
+            "label": "x86_64-unknown-linux-gnu"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn check(
+    initial: &str,
+    (path, label, name): (&str, &str, Option<&str>),
+    on_duplicate: OnDuplicateLabel,
+    expected_flag: ModifiedFlag,
+    expected: &str,
+) {
+    let mut manifest = SemanticReleaseManifest::from_str(initial).unwrap();
+    let modified = manifest.add_asset(path, label, name, on_duplicate).unwrap();
+    assert_eq!(expected_flag, modified);
+    assert_eq!(expected.trim(), manifest.to_string())
+}
+
+#[test]
+fn should_append_asset() {
+    check(
+        SEMANTIC_RELEASE_CONFIG,
+        (
+            "dist/riscv64gc-unknown-linux-gnu/BINARY_NAME-riscv64gc-unknown-linux-gnu",
+            "riscv64gc-unknown-linux-gnu",
+            Some("BINARY_NAME-riscv64gc-unknown-linux-gnu"),
+        ),
+        OnDuplicateLabel::Refuse,
+        ModifiedFlag::Modified,
+        r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-gnu/BINARY_NAME-x86_64-unknown-linux-gnu",
+            "label": "x86_64-unknown-linux-gnu"
+          },
+          {
+            "path": "dist/riscv64gc-unknown-linux-gnu/BINARY_NAME-riscv64gc-unknown-linux-gnu",
+            "label": "riscv64gc-unknown-linux-gnu",
+            "name": "BINARY_NAME-riscv64gc-unknown-linux-gnu"
+          }
+        ]
+      }
+    ]
+  ]
+}
+        "#,
+    )
+}
+
+#[test]
+fn should_create_plugin_configuration_when_missing() {
+    check(
+        r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    "@semantic-release/github"
+  ]
+}
+        "#,
+        ("dist/SHA256SUMS.txt", "SHA256SUMS.txt", None),
+        OnDuplicateLabel::Refuse,
+        ModifiedFlag::Modified,
+        r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/SHA256SUMS.txt",
+            "label": "SHA256SUMS.txt"
+          }
+        ]
+      }
+    ]
+  ]
+}
+        "#,
+    )
+}
+
+#[test]
+fn should_create_plugin_when_missing() {
+    check(
+        r#"
+{
+  "branches": [
+    "main"
+  ]
+}
+        "#,
+        ("dist/SHA256SUMS.txt", "SHA256SUMS.txt", None),
+        OnDuplicateLabel::Refuse,
+        ModifiedFlag::Modified,
+        r#"
+{
+  "branches": [
+    "main"
+  ],
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/SHA256SUMS.txt",
+            "label": "SHA256SUMS.txt"
+          }
+        ]
+      }
+    ]
+  ]
+}
+        "#,
+    )
+}
+
+#[test]
+fn should_refuse_duplicate_label() {
+    let mut manifest = SemanticReleaseManifest::from_str(SEMANTIC_RELEASE_CONFIG).unwrap();
+    let err = manifest
+        .add_asset(
+            "dist/elsewhere",
+            "x86_64-unknown-linux-gnu",
+            None,
+            OnDuplicateLabel::Refuse,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{}", err);
+}
+
+#[test]
+fn should_update_duplicate_label() {
+    check(
+        SEMANTIC_RELEASE_CONFIG,
+        ("dist/elsewhere", "x86_64-unknown-linux-gnu", None),
+        OnDuplicateLabel::Update,
+        ModifiedFlag::Modified,
+        r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/elsewhere",
+            "label": "x86_64-unknown-linux-gnu"
+          }
+        ]
+      }
+    ]
+  ]
+}
+        "#,
+    )
+}
+
+#[test]
+fn should_not_modify_when_updating_to_identical_asset() {
+    check(
+        SEMANTIC_RELEASE_CONFIG,
+        (
+            "dist/x86_64-unknown-linux-gnu/BINARY_NAME-x86_64-unknown-linux-gnu",
+            "x86_64-unknown-linux-gnu",
+            None,
+        ),
+        OnDuplicateLabel::Update,
+        ModifiedFlag::Unmodified,
+        SEMANTIC_RELEASE_CONFIG,
+    )
+}
@@ -0,0 +1,91 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::Output;
+
+mod common;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn project_in_temp_dir(test_name: &str) -> PathBuf {
+    common::project_in_temp_dir(test_name, &[(".releaserc.json", SEMANTIC_RELEASE_CONFIG)])
+}
+
+fn run(directory: &PathBuf, args: &[&str]) -> Output {
+    common::binary()
+        .arg("--directory")
+        .arg(directory)
+        .args(args)
+        .args(["whitelist", "aarch64-apple-darwin"])
+        .output()
+        .unwrap()
+}
+
+fn stdout(directory: &PathBuf, args: &[&str]) -> String {
+    let output = run(directory, args);
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn should_print_nothing_when_unmodified_by_default() {
+    let directory = project_in_temp_dir("always_write_default");
+
+    assert_eq!("", stdout(&directory, &[]));
+}
+
+#[test]
+fn should_print_unmodified_manifest_with_always_write() {
+    let directory = project_in_temp_dir("always_write");
+
+    assert_eq!(
+        SEMANTIC_RELEASE_CONFIG,
+        stdout(&directory, &["--always-write"])
+    );
+}
+
+#[test]
+fn should_accept_print_alias() {
+    let directory = project_in_temp_dir("always_write_print");
+
+    assert_eq!(SEMANTIC_RELEASE_CONFIG, stdout(&directory, &["--print"]));
+}
+
+#[test]
+fn should_refuse_always_write_with_another_destination() {
+    let directory = project_in_temp_dir("always_write_destination");
+
+    for args in [
+        &["--always-write", "--in-place"][..],
+        &["--always-write", "--output", "out.json"][..],
+    ] {
+        assert_eq!(Some(2), run(&directory, args).status.code(), "{:?}", args);
+    }
+}
+
+#[test]
+fn should_leave_unmodified_manifest_untouched_in_place() {
+    let directory = project_in_temp_dir("always_write_in_place_unmodified");
+    let path = directory.join(".releaserc.json");
+    let modified = fs::metadata(&path).unwrap().modified().unwrap();
+
+    assert_eq!("", stdout(&directory, &["--in-place", "--backup"]));
+
+    assert_eq!(SEMANTIC_RELEASE_CONFIG, fs::read_to_string(&path).unwrap());
+    assert_eq!(modified, fs::metadata(&path).unwrap().modified().unwrap());
+    assert!(!directory.join(".releaserc.json~").exists());
+}
@@ -0,0 +1,221 @@
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{
+    AssetField, AssetPattern, MatchOptions, ModifiedFlag, SemanticReleaseManifest,
+};
+
+fn patterns(patterns: &[&str]) -> Vec<AssetPattern> {
+    patterns
+        .iter()
+        .map(|pattern| AssetPattern::from_str(pattern).unwrap())
+        .collect()
+}
+
+fn check(initial: &str, field: AssetField, whitelist: &[&str], expected: &str) {
+    let mut manifest = SemanticReleaseManifest::from_str(initial).unwrap();
+    manifest.apply_whitelist_with(
+        MatchOptions {
+            field,
+            ..Default::default()
+        },
+        patterns(whitelist),
+    );
+    assert_eq!(expected.trim(), manifest.to_string())
+}
+
+#[test]
+fn should_match_string_assets_by_path() {
+    check(
+        r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          "dist/x86_64-unknown-linux-musl",
+          "dist/aarch64-apple-darwin",
+          {
+            "path": "dist/SHA256SUMS.txt",
+            "label": "SHA256SUMS.txt"
+          }
+        ]
+      }
+    ]
+  ]
+}
+        "#,
+        AssetField::Path,
+        &["dist/*-linux-*", "dist/SHA256SUMS.txt"],
+        r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          "dist/x86_64-unknown-linux-musl",
+          {
+            "path": "dist/SHA256SUMS.txt",
+            "label": "SHA256SUMS.txt"
+          }
+        ]
+      }
+    ]
+  ]
+}
+        "#,
+    )
+}
+
+#[test]
+fn should_pass_through_string_assets_when_matching_labels() {
+    let initial = r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          "dist/x86_64-unknown-linux-musl"
+        ]
+      }
+    ]
+  ]
+}
+    "#;
+    let mut manifest = SemanticReleaseManifest::from_str(initial).unwrap();
+    assert_eq!(
+        ModifiedFlag::Unmodified,
+        manifest.apply_whitelist(patterns(&[]))
+    );
+    assert_eq!(initial.trim(), manifest.to_string())
+}
+
+#[test]
+fn should_match_any_glob_in_a_path_array() {
+    check(
+        r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": [
+              "dist/**/*-linux-*",
+              "!dist/**/*.sha256"
+            ],
+            "label": "Linux binaries"
+          },
+          {
+            "path": [
+              "dist/**/*-darwin"
+            ],
+            "label": "macOS binaries"
+          }
+        ]
+      }
+    ]
+  ]
+}
+        "#,
+        AssetField::Path,
+        &["dist/*-darwin"],
+        r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": [
+              "dist/**/*-darwin"
+            ],
+            "label": "macOS binaries"
+          }
+        ]
+      }
+    ]
+  ]
+}
+        "#,
+    )
+}
+
+#[test]
+fn should_handle_a_single_string_in_place_of_an_assets_array() {
+    let initial = r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": "dist/*"
+      }
+    ]
+  ]
+}
+    "#;
+
+    check(initial, AssetField::Path, &["dist/*"], initial);
+    check(
+        initial,
+        AssetField::Path,
+        &["build/*"],
+        r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": []
+      }
+    ]
+  ]
+}
+        "#,
+    );
+}
+
+#[test]
+fn should_handle_a_single_object_in_place_of_an_assets_array() {
+    let initial = r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": {
+          "path": "dist/SHA256SUMS.txt",
+          "label": "SHA256SUMS.txt"
+        }
+      }
+    ]
+  ]
+}
+    "#;
+    let manifest = SemanticReleaseManifest::from_str(initial).unwrap();
+    assert_eq!(Some("SHA256SUMS.txt"), manifest.assets()[0].label);
+
+    check(initial, AssetField::Label, &["SHA256SUMS.txt"], initial);
+    check(
+        initial,
+        AssetField::Label,
+        &["*-linux-*"],
+        r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": []
+      }
+    ]
+  ]
+}
+        "#,
+    );
+}
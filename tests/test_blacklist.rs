@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{ModifiedFlag, SemanticReleaseManifest};
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl/BINARY_NAME-x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin/BINARY_NAME-aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          },
+          {
+            "path": "dist/SHA256SUMS.txt",
+            "label": "SHA256SUMS.txt"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn check(initial: &str, blacklist: &str, expected_flag: ModifiedFlag, expected: &str) {
+    let mut manifest = SemanticReleaseManifest::from_str(initial).unwrap();
+    let modified = manifest.apply_blacklist(HashSet::from_iter(
+        blacklist.split_whitespace().map(|s| s.to_owned()),
+    ));
+    assert_eq!(expected_flag, modified);
+    assert_eq!(expected.trim(), manifest.to_string())
+}
+
+#[test]
+fn should_not_change_file_when_blacklist_is_empty() {
+    check(
+        SEMANTIC_RELEASE_CONFIG,
+        "",
+        ModifiedFlag::Unmodified,
+        SEMANTIC_RELEASE_CONFIG,
+    )
+}
+
+#[test]
+fn should_not_change_file_when_blacklist_matches_nothing() {
+    check(
+        SEMANTIC_RELEASE_CONFIG,
+        "riscv64gc-unknown-linux-gnu",
+        ModifiedFlag::Unmodified,
+        SEMANTIC_RELEASE_CONFIG,
+    )
+}
+
+#[test]
+fn should_remove_only_blacklisted_assets() {
+    check(
+        SEMANTIC_RELEASE_CONFIG,
+        "x86_64-unknown-linux-musl SHA256SUMS.txt",
+        ModifiedFlag::Modified,
+        r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/aarch64-apple-darwin/BINARY_NAME-aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+    "#,
+    )
+}
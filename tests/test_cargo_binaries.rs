@@ -0,0 +1,197 @@
+use std::path::Path;
+
+use configure_semantic_release_assets::find_cargo_binaries;
+
+mod common;
+
+fn binaries(directory: &Path) -> Vec<String> {
+    find_cargo_binaries(directory).unwrap()
+}
+
+#[test]
+fn should_name_binary_after_package() {
+    let directory = common::project_in_temp_dir(
+        "cargo_package",
+        &[
+            (
+                "Cargo.toml",
+                "[package]\nname = \"tool\"\nversion = \"0.1.0\"\n",
+            ),
+            ("src/main.rs", "fn main() {}\n"),
+        ],
+    );
+
+    assert_eq!(vec!["tool"], binaries(&directory));
+}
+
+#[test]
+fn should_discover_binaries_in_src_bin() {
+    let directory = common::project_in_temp_dir(
+        "cargo_src_bin",
+        &[
+            ("Cargo.toml", "[package]\nname = \"tool\"\n"),
+            ("src/main.rs", "fn main() {}\n"),
+            ("src/bin/extra.rs", "fn main() {}\n"),
+            ("src/bin/helper/main.rs", "fn main() {}\n"),
+            ("src/bin/helper/util.rs", "\n"),
+            ("src/bin/notes.md", "Not a binary\n"),
+        ],
+    );
+
+    assert_eq!(vec!["tool", "extra", "helper"], binaries(&directory));
+}
+
+#[test]
+fn should_keep_src_main_alongside_bin_targets() {
+    let directory = common::project_in_temp_dir(
+        "cargo_bins_and_main",
+        &[
+            (
+                "Cargo.toml",
+                "[package]\nname = \"tool\"\n\n[[bin]]\nname = \"other\"\npath = \"src/other.rs\"\n",
+            ),
+            ("src/main.rs", "fn main() {}\n"),
+            ("src/other.rs", "fn main() {}\n"),
+        ],
+    );
+
+    assert_eq!(vec!["other", "tool"], binaries(&directory));
+}
+
+#[test]
+fn should_not_discover_binaries_without_autobins() {
+    let directory = common::project_in_temp_dir(
+        "cargo_autobins",
+        &[
+            (
+                "Cargo.toml",
+                "[package]\nname = \"tool\"\nautobins = false\n\n[[bin]]\nname = \"renamed\"\npath = \"src/main.rs\"\n",
+            ),
+            ("src/main.rs", "fn main() {}\n"),
+            ("src/bin/extra.rs", "fn main() {}\n"),
+        ],
+    );
+
+    assert_eq!(vec!["renamed"], binaries(&directory));
+}
+
+#[test]
+fn should_prefer_bin_targets() {
+    let directory = common::project_in_temp_dir(
+        "cargo_bins",
+        &[(
+            "Cargo.toml",
+            r#"
+[package]
+name = "tools"
+version = "0.1.0"
+
+[[bin]]
+name = "tool-a"
+path = "src/a.rs"
+
+[[bin]]
+name = "tool-b"
+path = "src/b.rs"
+"#,
+        )],
+    );
+
+    assert_eq!(vec!["tool-a", "tool-b"], binaries(&directory));
+}
+
+#[test]
+fn should_collect_binaries_of_workspace_members() {
+    let directory = common::project_in_temp_dir(
+        "cargo_workspace",
+        &[
+            (
+                "Cargo.toml",
+                r#"
+[workspace]
+members = ["cli", "crates/*"]
+exclude = ["crates/excluded"]
+"#,
+            ),
+            ("cli/Cargo.toml", "[package]\nname = \"cli\"\n"),
+            ("cli/src/main.rs", "fn main() {}\n"),
+            (
+                "crates/library/Cargo.toml",
+                "[package]\nname = \"library\"\n",
+            ),
+            ("crates/library/src/lib.rs", "\n"),
+            (
+                "crates/server/Cargo.toml",
+                "[package]\nname = \"server\"\n\n[[bin]]\nname = \"serverd\"\n",
+            ),
+            (
+                "crates/excluded/Cargo.toml",
+                "[package]\nname = \"excluded\"\n",
+            ),
+            ("crates/README.md", "Not a crate\n"),
+        ],
+    );
+
+    assert_eq!(vec!["cli", "serverd"], binaries(&directory));
+}
+
+#[test]
+fn should_refuse_manifest_without_package_or_workspace() {
+    let directory =
+        common::project_in_temp_dir("cargo_empty", &[("Cargo.toml", "[dependencies]\n")]);
+
+    let err = find_cargo_binaries(&directory).unwrap_err();
+    assert!(err.to_string().contains("\"package\""), "{}", err);
+}
+
+#[test]
+fn should_generate_assets_for_each_binary_and_target() {
+    let directory = common::project_in_temp_dir(
+        "cargo_generate",
+        &[
+            ("Cargo.toml", "[workspace]\nmembers = [\"a\", \"b\"]\n"),
+            ("a/Cargo.toml", "[package]\nname = \"a\"\n"),
+            ("a/src/main.rs", "fn main() {}\n"),
+            ("b/Cargo.toml", "[package]\nname = \"b\"\n"),
+            ("b/src/main.rs", "fn main() {}\n"),
+        ],
+    );
+
+    let output = common::run_with_stdin(
+        &[
+            "--directory",
+            directory.to_str().unwrap(),
+            "--input",
+            "-",
+            "--format",
+            "json",
+            "generate",
+            "--path",
+            "dist/{target}/{binary}",
+            "x86_64-unknown-linux-musl",
+            "aarch64-apple-darwin",
+        ],
+        r#"{"plugins": ["@semantic-release/github"]}"#,
+    );
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let labels: Vec<&str> = stdout
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("\"label\": "))
+        .map(|label| label.trim_end_matches(',').trim_matches('"'))
+        .collect();
+    assert_eq!(
+        vec![
+            "a-x86_64-unknown-linux-musl",
+            "a-aarch64-apple-darwin",
+            "b-x86_64-unknown-linux-musl",
+            "b-aarch64-apple-darwin",
+        ],
+        labels
+    );
+}
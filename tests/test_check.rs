@@ -0,0 +1,185 @@
+use std::fs;
+use std::path::PathBuf;
+
+mod common;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn project_in_temp_dir(test_name: &str) -> PathBuf {
+    project_with_config_in_temp_dir(test_name, SEMANTIC_RELEASE_CONFIG)
+}
+
+fn project_with_config_in_temp_dir(test_name: &str, contents: &str) -> PathBuf {
+    common::project_in_temp_dir(test_name, &[(".releaserc.json", contents)])
+}
+
+fn check(directory: &PathBuf, args: &[&str]) -> (Option<i32>, String) {
+    let output = common::binary()
+        .arg("--directory")
+        .arg(directory)
+        .arg("--check")
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn should_exit_successfully_when_manifest_is_up_to_date() {
+    let directory = project_in_temp_dir("check_up_to_date");
+
+    let (code, stdout) = check(
+        &directory,
+        &[
+            "whitelist",
+            "x86_64-unknown-linux-musl",
+            "aarch64-apple-darwin",
+        ],
+    );
+
+    assert_eq!(Some(0), code);
+    assert!(stdout.ends_with("is up to date\n"), "{}", stdout);
+}
+
+#[test]
+fn should_exit_with_distinct_status_when_manifest_would_change() {
+    let directory = project_in_temp_dir("check_modified");
+
+    let (code, stdout) = check(&directory, &["whitelist", "aarch64-apple-darwin"]);
+
+    assert_eq!(Some(3), code);
+    assert!(
+        stdout.ends_with(
+            "would be modified\n- x86_64-unknown-linux-musl (dist/x86_64-unknown-linux-musl)\n"
+        ),
+        "{}",
+        stdout
+    );
+    assert_eq!(
+        SEMANTIC_RELEASE_CONFIG,
+        fs::read_to_string(directory.join(".releaserc.json")).unwrap()
+    );
+}
+
+#[test]
+fn should_summarize_name_changes() {
+    let directory = project_in_temp_dir("check_name_change");
+
+    let (code, stdout) = check(
+        &directory,
+        &[
+            "add",
+            "--update",
+            "--path",
+            "dist/aarch64-apple-darwin",
+            "--label",
+            "aarch64-apple-darwin",
+            "--name",
+            "tool-aarch64-apple-darwin",
+        ],
+    );
+
+    assert_eq!(Some(3), code);
+    assert!(
+        stdout.ends_with(
+            "would be modified\n\
+             - aarch64-apple-darwin (dist/aarch64-apple-darwin)\n\
+             + aarch64-apple-darwin (dist/aarch64-apple-darwin) as tool-aarch64-apple-darwin\n"
+        ),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn should_summarize_removal_of_one_of_identical_assets() {
+    let directory = project_with_config_in_temp_dir(
+        "check_identical_assets",
+        r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          "dist/tool",
+          "dist/tool"
+        ]
+      }
+    ]
+  ]
+}
+"#,
+    );
+
+    let (code, stdout) = check(
+        &directory,
+        &[
+            "plugin",
+            "set",
+            "@semantic-release/github",
+            r#"{"assets": ["dist/tool"]}"#,
+        ],
+    );
+
+    assert_eq!(Some(3), code);
+    assert!(
+        stdout.ends_with("would be modified\n- dist/tool\n"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn should_summarize_plugin_changes() {
+    let directory = project_in_temp_dir("check_plugin_changes");
+
+    let (code, stdout) = check(&directory, &["plugin", "add", "semantic-release-major-tag"]);
+    assert_eq!(Some(3), code);
+    assert!(
+        stdout.ends_with("would be modified\n+ plugin semantic-release-major-tag\n"),
+        "{}",
+        stdout
+    );
+
+    let (code, stdout) = check(
+        &directory,
+        &[
+            "plugin",
+            "set",
+            "@semantic-release/github",
+            "successComment=false",
+        ],
+    );
+    assert_eq!(Some(3), code);
+    assert!(
+        stdout.ends_with(
+            "would be modified\n\
+             - plugin @semantic-release/github\n\
+             + plugin @semantic-release/github {\"successComment\":false}\n"
+        ),
+        "{}",
+        stdout
+    );
+}
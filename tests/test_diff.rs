@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+use std::iter::FromIterator;
+use std::path::PathBuf;
+
+use configure_semantic_release_assets::SemanticReleaseConfiguration;
+
+mod common;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn releaserc_in_temp_dir(test_name: &str) -> PathBuf {
+    common::file_in_temp_dir(test_name, ".releaserc.json", SEMANTIC_RELEASE_CONFIG)
+}
+
+#[test]
+fn should_render_unified_diff_of_pending_changes() {
+    let path = releaserc_in_temp_dir("diff");
+
+    let mut configuration = SemanticReleaseConfiguration::read_from_file(&path).unwrap();
+    configuration.apply_whitelist(HashSet::from_iter(["aarch64-apple-darwin".to_owned()]));
+
+    let path = path.display();
+    assert_eq!(
+        format!(
+            r#"--- {path}
++++ {path}
+@@ -6,10 +6,6 @@
+       {{
+         "assets": [
+           {{
+-            "path": "dist/x86_64-unknown-linux-musl",
+-            "label": "x86_64-unknown-linux-musl"
+-          }},
+-          {{
+             "path": "dist/aarch64-apple-darwin",
+             "label": "aarch64-apple-darwin"
+           }}
+"#
+        ),
+        configuration.diff().unwrap()
+    );
+}
+
+#[test]
+fn should_render_empty_diff_when_unmodified() {
+    let path = releaserc_in_temp_dir("diff_unmodified");
+
+    let configuration = SemanticReleaseConfiguration::read_from_file(&path).unwrap();
+
+    assert_eq!("", configuration.diff().unwrap());
+}
@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{
+    AssetField, AssetPlugin, MatchOptions, OnDuplicateLabel, SemanticReleaseManifest,
+};
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/gitlab",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl",
+            "type": "package",
+            "filepath": "/binaries/x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin",
+            "type": "package",
+            "filepath": "/binaries/aarch64-apple-darwin"
+          }
+        ]
+      }
+    ],
+    [
+      "@saithodev/semantic-release-gitea",
+      {
+        "assets": [
+          "dist/x86_64-unknown-linux-musl",
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "name": "tool-aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn manifest() -> SemanticReleaseManifest {
+    SemanticReleaseManifest::from_str(SEMANTIC_RELEASE_CONFIG).unwrap()
+}
+
+fn set(values: &[&str]) -> HashSet<String> {
+    HashSet::from_iter(values.iter().map(|value| value.to_string()))
+}
+
+#[test]
+fn should_look_up_plugins_by_name() {
+    for plugin in AssetPlugin::ALL {
+        assert_eq!(Some(*plugin), AssetPlugin::from_name(plugin.name()));
+    }
+    assert_eq!(None, AssetPlugin::from_name("@semantic-release/npm"));
+}
+
+#[test]
+fn should_whitelist_gitlab_assets_by_label_keeping_other_properties() {
+    let mut manifest = manifest();
+
+    manifest.apply_whitelist_with(
+        MatchOptions::for_plugin(AssetPlugin::Gitlab),
+        set(&["aarch64-apple-darwin"]),
+    );
+
+    assert_eq!(
+        r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/gitlab",
+      {
+        "assets": [
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin",
+            "type": "package",
+            "filepath": "/binaries/aarch64-apple-darwin"
+          }
+        ]
+      }
+    ],
+    [
+      "@saithodev/semantic-release-gitea",
+      {
+        "assets": [
+          "dist/x86_64-unknown-linux-musl",
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "name": "tool-aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#
+        .trim(),
+        manifest.to_string()
+    );
+}
+
+#[test]
+fn should_blacklist_gitea_assets_by_path() {
+    let mut manifest = manifest();
+    assert_eq!(AssetField::Path, AssetPlugin::Gitea.default_field());
+
+    manifest.apply_blacklist_with(
+        MatchOptions::for_plugin(AssetPlugin::Gitea),
+        set(&["dist/x86_64-unknown-linux-musl"]),
+    );
+
+    let assets = manifest.assets_in(AssetPlugin::Gitea);
+    assert_eq!(1, assets.len());
+    assert_eq!(Some("tool-aarch64-apple-darwin"), assets[0].name);
+    assert_eq!(2, manifest.assets_in(AssetPlugin::Gitlab).len());
+}
+
+#[test]
+fn should_add_assets_in_each_plugin_schema() {
+    let mut manifest = manifest();
+
+    manifest
+        .add_asset_in(
+            AssetPlugin::Gitlab,
+            "dist/riscv64gc-unknown-linux-gnu",
+            Some("riscv64gc-unknown-linux-gnu"),
+            None,
+            OnDuplicateLabel::Refuse,
+        )
+        .unwrap();
+    manifest
+        .add_asset_in(
+            AssetPlugin::Gitea,
+            "dist/riscv64gc-unknown-linux-gnu",
+            None,
+            Some("tool-riscv64gc-unknown-linux-gnu"),
+            OnDuplicateLabel::Refuse,
+        )
+        .unwrap();
+
+    let gitlab = manifest.assets_in(AssetPlugin::Gitlab);
+    assert_eq!(Some("riscv64gc-unknown-linux-gnu"), gitlab[2].label);
+    let gitea = manifest.assets_in(AssetPlugin::Gitea);
+    assert_eq!(Some("tool-riscv64gc-unknown-linux-gnu"), gitea[2].name);
+}
+
+#[test]
+fn should_refuse_properties_outside_plugin_schema() {
+    let mut manifest = manifest();
+
+    let err = manifest
+        .add_asset_in(
+            AssetPlugin::Gitlab,
+            "dist/a",
+            Some("a"),
+            Some("a.tar.gz"),
+            OnDuplicateLabel::Refuse,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("\"name\""), "{}", err);
+
+    let err = manifest
+        .add_asset_in(
+            AssetPlugin::Gitea,
+            "dist/a",
+            Some("a"),
+            None,
+            OnDuplicateLabel::Refuse,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("\"label\""), "{}", err);
+}
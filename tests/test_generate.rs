@@ -0,0 +1,182 @@
+use std::process::Output;
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{
+    AssetPlugin, AssetTemplate, ModifiedFlag, SemanticReleaseManifest,
+};
+
+mod common;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": ".semantic-release-action_rust/dist/x86_64-unknown-linux-musl/BINARY_NAME-x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": ".semantic-release-action_rust/dist/SHA256SUMS.txt",
+            "label": "SHA256SUMS.txt"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn generate(args: &[&str]) -> Output {
+    let args = [&["--input", "-", "--format", "json"], args].concat();
+    common::run_with_stdin(&args, SEMANTIC_RELEASE_CONFIG)
+}
+
+#[test]
+fn should_generate_assets_from_target_triples() {
+    let output = generate(&[
+        "generate",
+        "--binary",
+        "BINARY_NAME",
+        "x86_64-unknown-linux-musl",
+        "aarch64-apple-darwin",
+    ]);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        r#"{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": ".semantic-release-action_rust/dist/x86_64-unknown-linux-musl/BINARY_NAME-x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": ".semantic-release-action_rust/dist/aarch64-apple-darwin/BINARY_NAME-aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#,
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn should_generate_assets_from_custom_templates() {
+    let output = generate(&[
+        "generate",
+        "--path",
+        "target/{target}/release/{binary}",
+        "--label",
+        "{binary} ({target})",
+        "--name",
+        "{binary}-{target}",
+        "--binary",
+        "tool",
+        "x86_64-pc-windows-msvc",
+    ]);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains(
+            r#"{
+            "path": "target/x86_64-pc-windows-msvc/release/tool",
+            "label": "tool (x86_64-pc-windows-msvc)",
+            "name": "tool-x86_64-pc-windows-msvc"
+          }"#
+        ),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn should_refuse_binary_placeholder_without_binary() {
+    let directory = common::temp_dir("generate_without_binary");
+
+    let output = generate(&[
+        "--directory",
+        directory.to_str().unwrap(),
+        "generate",
+        "x86_64-unknown-linux-musl",
+    ]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("Cargo.toml"));
+}
+
+#[test]
+fn should_not_modify_matching_assets() {
+    let mut manifest = SemanticReleaseManifest::from_str(SEMANTIC_RELEASE_CONFIG).unwrap();
+    let template = AssetTemplate::default();
+    let targets = ["x86_64-unknown-linux-musl", "aarch64-apple-darwin"];
+
+    assert_eq!(
+        ModifiedFlag::Modified,
+        manifest
+            .generate_assets_in(AssetPlugin::Github, &template, &["tool"], &targets)
+            .unwrap()
+    );
+    assert_eq!(
+        ModifiedFlag::Unmodified,
+        manifest
+            .generate_assets_in(AssetPlugin::Github, &template, &["tool"], &targets)
+            .unwrap()
+    );
+    assert_eq!(2, manifest.assets().len());
+}
+
+#[test]
+fn should_generate_git_assets_as_paths() {
+    let mut manifest = SemanticReleaseManifest::from_str(r#"{"plugins": []}"#).unwrap();
+    let template = AssetTemplate {
+        label: None,
+        ..AssetTemplate::default()
+    };
+
+    manifest
+        .generate_assets_in(
+            AssetPlugin::Git,
+            &template,
+            &["tool"],
+            &["x86_64-unknown-linux-musl"],
+        )
+        .unwrap();
+
+    assert_eq!(
+        r#"{
+  "plugins": [
+    [
+      "@semantic-release/git",
+      {
+        "assets": [
+          ".semantic-release-action_rust/dist/x86_64-unknown-linux-musl/tool-x86_64-unknown-linux-musl"
+        ]
+      }
+    ]
+  ]
+}"#,
+        manifest.to_string()
+    );
+}
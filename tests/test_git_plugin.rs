@@ -0,0 +1,230 @@
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{
+    AssetField, AssetPattern, AssetPlugin, MatchOptions, ModifiedFlag, OnDuplicateLabel,
+    SemanticReleaseManifest,
+};
+
+mod common;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/Cargo.toml",
+            "label": "Cargo.toml"
+          }
+        ]
+      }
+    ],
+    [
+      "@semantic-release/git",
+      {
+        "assets": [
+          "CHANGELOG.md",
+          "Cargo.toml",
+          "Cargo.lock"
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn manifest() -> SemanticReleaseManifest {
+    SemanticReleaseManifest::from_str(SEMANTIC_RELEASE_CONFIG).unwrap()
+}
+
+fn patterns(patterns: &[&str]) -> Vec<AssetPattern> {
+    patterns
+        .iter()
+        .map(|pattern| AssetPattern::from_str(pattern).unwrap())
+        .collect()
+}
+
+fn git_assets(manifest: &SemanticReleaseManifest) -> Vec<&str> {
+    manifest
+        .assets_in(AssetPlugin::Git)
+        .into_iter()
+        .flat_map(|asset| asset.path)
+        .collect()
+}
+
+#[test]
+fn should_whitelist_git_assets_by_path() {
+    let mut manifest = manifest();
+
+    assert_eq!(
+        ModifiedFlag::Modified,
+        manifest.apply_whitelist_with(
+            MatchOptions::for_plugin(AssetPlugin::Git),
+            patterns(&["Cargo.*"]),
+        )
+    );
+
+    assert_eq!(vec!["Cargo.toml", "Cargo.lock"], git_assets(&manifest));
+    assert_eq!(1, manifest.assets().len());
+}
+
+#[test]
+fn should_blacklist_git_assets_by_path() {
+    let mut manifest = manifest();
+
+    manifest.apply_blacklist_with(
+        MatchOptions {
+            plugin: AssetPlugin::Git,
+            field: AssetField::Path,
+        },
+        patterns(&["*.md"]),
+    );
+
+    assert_eq!(vec!["Cargo.toml", "Cargo.lock"], git_assets(&manifest));
+}
+
+#[test]
+fn should_add_git_asset_as_string() {
+    let mut manifest = manifest();
+
+    assert_eq!(
+        ModifiedFlag::Modified,
+        manifest
+            .add_asset_in(
+                AssetPlugin::Git,
+                "README.md",
+                None,
+                None,
+                OnDuplicateLabel::Refuse
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        ModifiedFlag::Unmodified,
+        manifest
+            .add_asset_in(
+                AssetPlugin::Git,
+                "README.md",
+                None,
+                None,
+                OnDuplicateLabel::Update
+            )
+            .unwrap()
+    );
+
+    assert_eq!(
+        vec!["CHANGELOG.md", "Cargo.toml", "Cargo.lock", "README.md"],
+        git_assets(&manifest)
+    );
+}
+
+#[test]
+fn should_refuse_duplicate_git_asset() {
+    let mut manifest = manifest();
+
+    let err = manifest
+        .add_asset_in(
+            AssetPlugin::Git,
+            "Cargo.lock",
+            None,
+            None,
+            OnDuplicateLabel::Refuse,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{}", err);
+}
+
+#[test]
+fn should_refuse_label_on_git_asset() {
+    let mut manifest = manifest();
+
+    let err = manifest
+        .add_asset_in(
+            AssetPlugin::Git,
+            "README.md",
+            Some("readme"),
+            None,
+            OnDuplicateLabel::Refuse,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("\"label\""), "{}", err);
+}
+
+#[test]
+fn should_create_git_plugin_when_adding_asset() {
+    let mut manifest = SemanticReleaseManifest::from_str(r#"{"plugins": []}"#).unwrap();
+
+    manifest
+        .add_asset_in(
+            AssetPlugin::Git,
+            "CHANGELOG.md",
+            None,
+            None,
+            OnDuplicateLabel::Refuse,
+        )
+        .unwrap();
+
+    assert_eq!(
+        r#"{
+  "plugins": [
+    [
+      "@semantic-release/git",
+      {
+        "assets": [
+          "CHANGELOG.md"
+        ]
+      }
+    ]
+  ]
+}"#,
+        manifest.to_string()
+    );
+}
+
+#[test]
+fn should_target_git_plugin_from_command_line() {
+    let output = common::run_with_stdin(
+        &[
+            "--input",
+            "-",
+            "--format",
+            "json",
+            "--plugin",
+            "git",
+            "blacklist",
+            "Cargo.*",
+        ],
+        SEMANTIC_RELEASE_CONFIG.trim_start(),
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/Cargo.toml",
+            "label": "Cargo.toml"
+          }
+        ]
+      }
+    ],
+    [
+      "@semantic-release/git",
+      {
+        "assets": [
+          "CHANGELOG.md"
+        ]
+      }
+    ]
+  ]
+}
+"#,
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{
+    GithubAsset, GithubPluginConfig, ModifiedFlag, SemanticReleaseManifest,
+};
+
+fn check_round_trip(json: &str) {
+    let config: GithubPluginConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(json, serde_json::to_string(&config).unwrap());
+}
+
+#[test]
+fn should_round_trip_unknown_fields_and_key_order() {
+    check_round_trip(
+        r#"{"successComment":false,"assets":[{"label":"a","path":"dist/a","draft":true},{"path":["dist/b","dist/c"]}],"proxy":{"host":"example.com"}}"#,
+    );
+}
+
+#[test]
+fn should_round_trip_string_forms() {
+    check_round_trip(r#"{"assets":"dist/*.tar.gz"}"#);
+    check_round_trip(r#"{"assets":["dist/a",{"path":"dist/b","name":"b"}]}"#);
+    check_round_trip(r#"{"assets":[{"path":["dist/a"]}]}"#);
+    check_round_trip(r#"{}"#);
+}
+
+#[test]
+fn should_expose_typed_assets() {
+    let config: GithubPluginConfig = serde_json::from_str(
+        r#"{"assets":["dist/a",{"path":["dist/b","dist/c"],"label":"b","name":"b.tar.gz"}],"failTitle":false}"#,
+    )
+    .unwrap();
+
+    let mut b = GithubAsset::new("dist/b");
+    b.path.push("dist/c".to_owned());
+    b.label = Some("b".to_owned());
+    b.name = Some("b.tar.gz".to_owned());
+    assert_eq!(vec![GithubAsset::new("dist/a"), b], config.assets);
+    assert_eq!(
+        Some(&serde_json::Value::Bool(false)),
+        config.extra.get("failTitle")
+    );
+}
+
+#[test]
+fn should_reject_malformed_assets() {
+    for json in [
+        r#"{"assets":42}"#,
+        r#"{"assets":[42]}"#,
+        r#"{"assets":[{"path":"dist/a","label":7}]}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<GithubPluginConfig>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+}
+
+#[test]
+fn should_read_and_write_github_plugin_config_of_manifest() {
+    let mut manifest = SemanticReleaseManifest::from_str(
+        r#"{"plugins":["@semantic-release/npm",["@semantic-release/github",{"assets":"dist/*","successComment":false}]]}"#,
+    )
+    .unwrap();
+
+    let mut config = manifest.github_plugin_config().unwrap().unwrap();
+    assert_eq!(vec![GithubAsset::new("dist/*")], config.assets);
+
+    assert_eq!(
+        ModifiedFlag::Unmodified,
+        manifest.set_github_plugin_config(config.clone()).unwrap()
+    );
+
+    config.assets[0].label = Some("everything".to_owned());
+    assert_eq!(
+        ModifiedFlag::Modified,
+        manifest.set_github_plugin_config(config).unwrap()
+    );
+    assert_eq!(
+        r#"{
+  "plugins": [
+    "@semantic-release/npm",
+    [
+      "@semantic-release/github",
+      {
+        "assets": {
+          "path": "dist/*",
+          "label": "everything"
+        },
+        "successComment": false
+      }
+    ]
+  ]
+}"#,
+        manifest.to_string()
+    );
+}
+
+#[test]
+fn should_add_github_plugin_when_setting_config() {
+    let mut manifest = SemanticReleaseManifest::from_str(r#"{"branches":["main"]}"#).unwrap();
+    assert_eq!(None, manifest.github_plugin_config().unwrap());
+
+    let mut config = GithubPluginConfig::default();
+    config.assets.push(GithubAsset::new("dist/a"));
+    assert_eq!(
+        ModifiedFlag::Modified,
+        manifest.set_github_plugin_config(config).unwrap()
+    );
+    assert_eq!(
+        r#"{
+  "branches": [
+    "main"
+  ],
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/a"
+          }
+        ]
+      }
+    ]
+  ]
+}"#,
+        manifest.to_string()
+    );
+}
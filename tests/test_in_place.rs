@@ -0,0 +1,130 @@
+use std::fs;
+use std::path::PathBuf;
+
+mod common;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+const EXPECTED: &str = r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn project_in_temp_dir(test_name: &str) -> PathBuf {
+    common::project_in_temp_dir(test_name, &[(".releaserc.json", SEMANTIC_RELEASE_CONFIG)])
+}
+
+fn whitelist_in_place(directory: &PathBuf, args: &[&str]) {
+    let status = common::binary()
+        .arg("--directory")
+        .arg(directory)
+        .arg("--in-place")
+        .args(args)
+        .args(["whitelist", "aarch64-apple-darwin"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+fn file_names(directory: &PathBuf) -> Vec<String> {
+    let mut names: Vec<_> = fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn should_replace_manifest_without_leaving_temporary_files() {
+    let directory = project_in_temp_dir("in_place");
+
+    whitelist_in_place(&directory, &[]);
+
+    assert_eq!(
+        EXPECTED,
+        fs::read_to_string(directory.join(".releaserc.json")).unwrap()
+    );
+    assert_eq!(vec![".releaserc.json"], file_names(&directory));
+}
+
+#[test]
+fn should_keep_backup_with_default_suffix() {
+    let directory = project_in_temp_dir("in_place_backup");
+
+    whitelist_in_place(&directory, &["--backup"]);
+
+    assert_eq!(
+        EXPECTED,
+        fs::read_to_string(directory.join(".releaserc.json")).unwrap()
+    );
+    assert_eq!(
+        SEMANTIC_RELEASE_CONFIG,
+        fs::read_to_string(directory.join(".releaserc.json~")).unwrap()
+    );
+}
+
+#[test]
+fn should_keep_backup_with_custom_suffix() {
+    let directory = project_in_temp_dir("in_place_backup_suffix");
+
+    whitelist_in_place(&directory, &["--backup=.orig"]);
+
+    assert_eq!(
+        SEMANTIC_RELEASE_CONFIG,
+        fs::read_to_string(directory.join(".releaserc.json.orig")).unwrap()
+    );
+    assert_eq!(
+        vec![".releaserc.json", ".releaserc.json.orig"],
+        file_names(&directory)
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn should_preserve_file_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let directory = project_in_temp_dir("in_place_permissions");
+    let path = directory.join(".releaserc.json");
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+    whitelist_in_place(&directory, &[]);
+
+    assert_eq!(EXPECTED, fs::read_to_string(&path).unwrap());
+    assert_eq!(
+        0o640,
+        fs::metadata(&path).unwrap().permissions().mode() & 0o777
+    );
+}
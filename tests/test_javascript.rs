@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+use std::fs;
+use std::iter::FromIterator;
+use std::path::PathBuf;
+
+use configure_semantic_release_assets::{ManifestFormat, SemanticReleaseConfiguration, WriteTo};
+
+mod common;
+
+fn check(path: &PathBuf, whitelist: &str, expected: &str) {
+    let mut configuration = SemanticReleaseConfiguration::read_from_file(path).unwrap();
+    assert_eq!(ManifestFormat::JavaScript, configuration.format());
+
+    configuration.apply_whitelist(HashSet::from_iter(
+        whitelist.split_whitespace().map(|s| s.to_owned()),
+    ));
+    configuration.write_if_modified(WriteTo::InPlace).unwrap();
+
+    assert_eq!(expected, fs::read_to_string(path).unwrap());
+}
+
+#[test]
+fn should_edit_module_exports_object_literal() {
+    let path = common::file_in_temp_dir(
+        "module_exports",
+        "release.config.js",
+        r#"// semantic-release configuration
+const unused = "module.exports = 'not this one'";
+
+module.exports = {
+  branches: ['main'],
+  plugins: [
+    '@semantic-release/commit-analyzer',
+    [
+      '@semantic-release/github',
+      {
+        assets: [
+          { path: 'dist/x86_64-unknown-linux-musl', label: 'x86_64-unknown-linux-musl' },
+          { path: 'dist/aarch64-apple-darwin', label: 'aarch64-apple-darwin' },
+        ],
+      },
+    ],
+  ],
+};
+"#,
+    );
+
+    check(
+        &path,
+        "aarch64-apple-darwin",
+        r#"// semantic-release configuration
+const unused = "module.exports = 'not this one'";
+
+module.exports = {
+  branches: ['main'],
+  plugins: [
+    '@semantic-release/commit-analyzer',
+    [
+      '@semantic-release/github',
+      {
+        assets: [
+          { path: 'dist/aarch64-apple-darwin', label: 'aarch64-apple-darwin' },
+        ],
+      },
+    ],
+  ],
+};
+"#,
+    );
+}
+
+#[test]
+fn should_edit_export_default_object_literal() {
+    let path = common::file_in_temp_dir(
+        "export_default",
+        "release.config.mjs",
+        r#"export default {
+  "plugins": [
+    ["@semantic-release/github", { "assets": [{ "path": "dist/a", "label": "a" }, { "path": "dist/b", "label": "b" }] }]
+  ]
+}
+"#,
+    );
+
+    check(
+        &path,
+        "b",
+        r#"export default {
+  "plugins": [
+    ["@semantic-release/github", { "assets": [{ "path": "dist/b", "label": "b" }] }]
+  ]
+}
+"#,
+    );
+}
+
+#[test]
+fn should_refuse_dynamic_exports() {
+    for (test_name, contents) in [
+        ("dynamic_identifier", "module.exports = config;\n"),
+        (
+            "dynamic_value",
+            "module.exports = { branches: [process.env.BRANCH] };\n",
+        ),
+        ("dynamic_spread", "export default { ...base };\n"),
+        (
+            "dynamic_template",
+            "module.exports = { tagFormat: `v${version}` };\n",
+        ),
+    ] {
+        let path = common::file_in_temp_dir(test_name, "release.config.js", contents);
+        let err = match SemanticReleaseConfiguration::read_from_file(&path) {
+            Ok(_) => panic!("expected {:?} to be rejected", contents),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains("not a static object literal"),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{Asset, SemanticReleaseManifest};
+
+#[test]
+fn should_list_every_asset_shape() {
+    let manifest = SemanticReleaseManifest::from_str(
+        r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/aarch64-apple-darwin/BINARY_NAME",
+            "name": "BINARY_NAME-aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          },
+          "dist/SHA256SUMS.txt",
+          {
+            "path": ["dist/**/*.tar.gz", "!dist/**/debug.tar.gz"]
+          }
+        ]
+      }
+    ],
+    [
+      "@semantic-release/git",
+      {
+        "assets": ["CHANGELOG.md"]
+      }
+    ]
+  ]
+}
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec![
+            Asset {
+                path: vec!["dist/aarch64-apple-darwin/BINARY_NAME"],
+                label: Some("aarch64-apple-darwin"),
+                name: Some("BINARY_NAME-aarch64-apple-darwin"),
+            },
+            Asset {
+                path: vec!["dist/SHA256SUMS.txt"],
+                label: None,
+                name: None,
+            },
+            Asset {
+                path: vec!["dist/**/*.tar.gz", "!dist/**/debug.tar.gz"],
+                label: None,
+                name: None,
+            },
+        ],
+        manifest.assets()
+    );
+}
+
+#[test]
+fn should_list_nothing_without_github_plugin() {
+    let manifest = SemanticReleaseManifest::from_str(r#"{ "branches": ["main"] }"#).unwrap();
+
+    assert_eq!(Vec::<Asset>::new(), manifest.assets());
+}
@@ -0,0 +1,96 @@
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{
+    AssetField, AssetPattern, MatchOptions, SemanticReleaseManifest,
+};
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl/BINARY_NAME",
+            "name": "BINARY_NAME-x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin/BINARY_NAME",
+            "name": "BINARY_NAME-aarch64-apple-darwin"
+          },
+          {
+            "path": "dist/SHA256SUMS.txt",
+            "label": "SHA256SUMS.txt"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn paths_after_whitelist(field: AssetField, whitelist: &[&str]) -> Vec<String> {
+    let mut manifest = SemanticReleaseManifest::from_str(SEMANTIC_RELEASE_CONFIG).unwrap();
+    let patterns: Vec<AssetPattern> = whitelist
+        .iter()
+        .map(|pattern| AssetPattern::from_str(pattern).unwrap())
+        .collect();
+    manifest.apply_whitelist_with(
+        MatchOptions {
+            field,
+            ..Default::default()
+        },
+        patterns,
+    );
+
+    let rendered: serde_json::Value = serde_json::from_str(&manifest.to_string()).unwrap();
+    rendered["plugins"][0][1]["assets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|asset| asset["path"].as_str().unwrap().to_owned())
+        .collect()
+}
+
+#[test]
+fn should_pass_through_assets_without_a_label_when_matching_labels() {
+    assert_eq!(
+        vec![
+            "dist/aarch64-apple-darwin/BINARY_NAME",
+            "dist/SHA256SUMS.txt"
+        ],
+        paths_after_whitelist(AssetField::Label, &["SHA256SUMS.txt"])
+    );
+}
+
+#[test]
+fn should_match_paths_with_globs() {
+    assert_eq!(
+        vec!["dist/x86_64-unknown-linux-musl/BINARY_NAME"],
+        paths_after_whitelist(AssetField::Path, &["dist/*-linux-*/*"])
+    );
+}
+
+#[test]
+fn should_match_names() {
+    assert_eq!(
+        vec![
+            "dist/aarch64-apple-darwin/BINARY_NAME",
+            "dist/SHA256SUMS.txt"
+        ],
+        paths_after_whitelist(AssetField::Name, &["*-apple-darwin"])
+    );
+}
+
+#[test]
+fn should_match_any_field() {
+    assert_eq!(
+        vec![
+            "dist/aarch64-apple-darwin/BINARY_NAME",
+            "dist/SHA256SUMS.txt"
+        ],
+        paths_after_whitelist(AssetField::Any, &["*-apple-darwin", "dist/SHA256SUMS.txt"])
+    );
+}
@@ -0,0 +1,92 @@
+use std::fs;
+use std::path::PathBuf;
+
+mod common;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn project_in_temp_dir(test_name: &str) -> PathBuf {
+    common::project_in_temp_dir(test_name, &[(".releaserc.json", SEMANTIC_RELEASE_CONFIG)])
+}
+
+fn whitelist_to_output(directory: &PathBuf, output: &PathBuf, whitelist: &[&str]) {
+    let status = common::binary()
+        .arg("--directory")
+        .arg(directory)
+        .arg("--output")
+        .arg(output)
+        .arg("whitelist")
+        .args(whitelist)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn should_write_edited_manifest_to_output_file() {
+    let directory = project_in_temp_dir("output");
+    let output = directory.join("darwin.releaserc.json");
+
+    whitelist_to_output(&directory, &output, &["aarch64-apple-darwin"]);
+
+    assert_eq!(
+        r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#,
+        fs::read_to_string(&output).unwrap()
+    );
+    assert_eq!(
+        SEMANTIC_RELEASE_CONFIG,
+        fs::read_to_string(directory.join(".releaserc.json")).unwrap()
+    );
+}
+
+#[test]
+fn should_write_output_file_even_when_unmodified() {
+    let directory = project_in_temp_dir("output_unmodified");
+    let output = directory.join("all.releaserc.json");
+    fs::write(&output, "stale").unwrap();
+
+    whitelist_to_output(
+        &directory,
+        &output,
+        &["x86_64-unknown-linux-musl", "aarch64-apple-darwin"],
+    );
+
+    assert_eq!(
+        SEMANTIC_RELEASE_CONFIG,
+        fs::read_to_string(&output).unwrap()
+    );
+}
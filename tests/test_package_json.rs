@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+use std::fs;
+use std::iter::FromIterator;
+use std::path::PathBuf;
+
+use configure_semantic_release_assets::{SemanticReleaseConfiguration, WriteTo};
+
+mod common;
+
+const PACKAGE_JSON: &str = r#"{
+  "name": "example",
+  "version": "0.0.0-development",
+  "scripts": {
+    "test": "cargo test"
+  },
+  "release": {
+    "plugins": [
+      "@semantic-release/commit-analyzer",
+      [
+        "@semantic-release/github",
+        {
+          "assets": [
+            {
+              "path": "dist/x86_64-unknown-linux-musl",
+              "label": "x86_64-unknown-linux-musl"
+            },
+            {
+              "path": "dist/aarch64-apple-darwin",
+              "label": "aarch64-apple-darwin"
+            }
+          ]
+        }
+      ]
+    ]
+  },
+  "devDependencies": {
+    "semantic-release": "^21.0.0"
+  }
+}
+"#;
+
+fn package_json_in_temp_dir(test_name: &str, contents: &str) -> PathBuf {
+    common::file_in_temp_dir(test_name, "package.json", contents)
+}
+
+#[test]
+fn should_edit_release_key_and_preserve_the_rest_of_package_json() {
+    let path = package_json_in_temp_dir("edit_release_key", PACKAGE_JSON);
+
+    let mut configuration = SemanticReleaseConfiguration::read_from_file(&path).unwrap();
+    configuration.apply_whitelist(HashSet::from_iter(["aarch64-apple-darwin".to_owned()]));
+    configuration.write_if_modified(WriteTo::InPlace).unwrap();
+
+    assert_eq!(
+        r#"{
+  "name": "example",
+  "version": "0.0.0-development",
+  "scripts": {
+    "test": "cargo test"
+  },
+  "release": {
+    "plugins": [
+      "@semantic-release/commit-analyzer",
+      [
+        "@semantic-release/github",
+        {
+          "assets": [
+            {
+              "path": "dist/aarch64-apple-darwin",
+              "label": "aarch64-apple-darwin"
+            }
+          ]
+        }
+      ]
+    ]
+  },
+  "devDependencies": {
+    "semantic-release": "^21.0.0"
+  }
+}
+"#,
+        fs::read_to_string(&path).unwrap()
+    );
+}
+
+#[test]
+fn should_reject_package_json_without_release_object() {
+    let path = package_json_in_temp_dir("missing_release_key", r#"{ "name": "example" }"#);
+
+    assert!(SemanticReleaseConfiguration::read_from_file(&path).is_err());
+}
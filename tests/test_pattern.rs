@@ -0,0 +1,97 @@
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{AssetPattern, SemanticReleaseManifest};
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl/BINARY_NAME-x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/x86_64-unknown-linux-gnu/BINARY_NAME-x86_64-unknown-linux-gnu",
+            "label": "x86_64-unknown-linux-gnu"
+          },
+          {
+            "path": "dist/aarch64-unknown-linux-gnu/BINARY_NAME-aarch64-unknown-linux-gnu",
+            "label": "aarch64-unknown-linux-gnu"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin/BINARY_NAME-aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          },
+          {
+            "path": "dist/SHA256SUMS.txt",
+            "label": "SHA256SUMS.txt"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn labels_after_whitelist(whitelist: &[&str]) -> Vec<String> {
+    let mut manifest = SemanticReleaseManifest::from_str(SEMANTIC_RELEASE_CONFIG).unwrap();
+    let patterns: Vec<AssetPattern> = whitelist
+        .iter()
+        .map(|pattern| AssetPattern::from_str(pattern).unwrap())
+        .collect();
+    manifest.apply_whitelist(patterns);
+
+    let rendered: serde_json::Value = serde_json::from_str(&manifest.to_string()).unwrap();
+    rendered["plugins"][0][1]["assets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|asset| asset["label"].as_str().unwrap().to_owned())
+        .collect()
+}
+
+#[test]
+fn should_match_exact_labels() {
+    assert_eq!(
+        vec!["aarch64-apple-darwin"],
+        labels_after_whitelist(&["aarch64-apple-darwin"])
+    );
+}
+
+#[test]
+fn should_match_glob_patterns() {
+    assert_eq!(
+        vec![
+            "x86_64-unknown-linux-musl",
+            "x86_64-unknown-linux-gnu",
+            "aarch64-unknown-linux-gnu",
+            "SHA256SUMS.txt"
+        ],
+        labels_after_whitelist(&["*-unknown-linux-*", "SHA256SUMS.txt"])
+    );
+}
+
+#[test]
+fn should_match_prefixed_regular_expressions() {
+    assert_eq!(
+        vec!["aarch64-unknown-linux-gnu", "aarch64-apple-darwin"],
+        labels_after_whitelist(&["regex:aarch64-.+"])
+    );
+}
+
+#[test]
+fn should_anchor_regular_expressions_to_the_whole_label() {
+    assert_eq!(
+        Vec::<String>::new(),
+        labels_after_whitelist(&["regex:linux"])
+    );
+}
+
+#[test]
+fn should_reject_invalid_patterns() {
+    assert!(AssetPattern::from_str("[").is_err());
+    assert!(AssetPattern::from_str("regex:(").is_err());
+}
@@ -0,0 +1,211 @@
+use std::process::Output;
+
+mod common;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    "@semantic-release/release-notes-generator",
+    [
+      "@semantic-release/npm",
+      {
+        "npmPublish": false
+      }
+    ],
+    "@semantic-release/github"
+  ]
+}
+"#;
+
+fn plugin(args: &[&str]) -> Output {
+    let args = [&["--input", "-", "--format", "json", "plugin"], args].concat();
+    common::run_with_stdin(&args, SEMANTIC_RELEASE_CONFIG)
+}
+
+fn check(args: &[&str], expected: &str) {
+    let output = plugin(args);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(expected, String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn should_add_plugin_at_index() {
+    check(
+        &["add", "semantic-release-major-tag", "--index", "3"],
+        r#"{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    "@semantic-release/release-notes-generator",
+    [
+      "@semantic-release/npm",
+      {
+        "npmPublish": false
+      }
+    ],
+    "semantic-release-major-tag",
+    "@semantic-release/github"
+  ]
+}
+"#,
+    );
+}
+
+#[test]
+fn should_add_plugin_with_options() {
+    check(
+        &[
+            "add",
+            "@semantic-release/exec",
+            "publishCmd=./publish.sh",
+            r#"{"dryRun": true}"#,
+        ],
+        r#"{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    "@semantic-release/release-notes-generator",
+    [
+      "@semantic-release/npm",
+      {
+        "npmPublish": false
+      }
+    ],
+    "@semantic-release/github",
+    [
+      "@semantic-release/exec",
+      {
+        "publishCmd": "./publish.sh",
+        "dryRun": true
+      }
+    ]
+  ]
+}
+"#,
+    );
+}
+
+#[test]
+fn should_remove_plugin() {
+    check(
+        &["remove", "@semantic-release/npm"],
+        r#"{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    "@semantic-release/release-notes-generator",
+    "@semantic-release/github"
+  ]
+}
+"#,
+    );
+}
+
+#[test]
+fn should_move_plugin() {
+    check(
+        &["move", "@semantic-release/github", "0"],
+        r#"{
+  "plugins": [
+    "@semantic-release/github",
+    "@semantic-release/commit-analyzer",
+    "@semantic-release/release-notes-generator",
+    [
+      "@semantic-release/npm",
+      {
+        "npmPublish": false
+      }
+    ]
+  ]
+}
+"#,
+    );
+}
+
+#[test]
+fn should_set_plugin_options() {
+    check(
+        &[
+            "set",
+            "@semantic-release/npm",
+            "npmPublish=true",
+            "tarballDir=dist",
+        ],
+        r#"{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    "@semantic-release/release-notes-generator",
+    [
+      "@semantic-release/npm",
+      {
+        "npmPublish": true,
+        "tarballDir": "dist"
+      }
+    ],
+    "@semantic-release/github"
+  ]
+}
+"#,
+    );
+}
+
+#[test]
+fn should_set_options_of_string_plugin() {
+    check(
+        &[
+            "set",
+            "@semantic-release/commit-analyzer",
+            r#"{"preset": "conventionalcommits"}"#,
+        ],
+        r#"{
+  "plugins": [
+    [
+      "@semantic-release/commit-analyzer",
+      {
+        "preset": "conventionalcommits"
+      }
+    ],
+    "@semantic-release/release-notes-generator",
+    [
+      "@semantic-release/npm",
+      {
+        "npmPublish": false
+      }
+    ],
+    "@semantic-release/github"
+  ]
+}
+"#,
+    );
+}
+
+#[test]
+fn should_refuse_to_configure_missing_plugin() {
+    let output = plugin(&["set", "@semantic-release/git", "message=release"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("No plugin named \"@semantic-release/git\""));
+}
+
+#[test]
+fn should_refuse_malformed_options() {
+    let output = plugin(&["set", "@semantic-release/npm", "npmPublish"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("key=value"));
+}
+
+#[test]
+fn should_refuse_to_remove_missing_plugin() {
+    let output = plugin(&["remove", "@semantic-release/typo"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("No plugin named \"@semantic-release/typo\""));
+}
@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+use configure_semantic_release_assets::{Plugin, SemanticReleaseManifest};
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    [
+      "@semantic-release/npm",
+      {
+        "npmPublish": false
+      }
+    ]
+  ]
+}
+"#;
+
+fn manifest() -> SemanticReleaseManifest {
+    SemanticReleaseManifest::from_str(SEMANTIC_RELEASE_CONFIG).unwrap()
+}
+
+fn object(json: &str) -> serde_json::Map<String, serde_json::Value> {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn should_list_plugins_in_order() {
+    let manifest = manifest();
+    let npm = object(r#"{"npmPublish": false}"#);
+
+    assert_eq!(
+        vec![
+            Plugin {
+                name: "@semantic-release/commit-analyzer",
+                configuration: None,
+            },
+            Plugin {
+                name: "@semantic-release/npm",
+                configuration: Some(&npm),
+            },
+        ],
+        manifest.plugins()
+    );
+    assert_eq!(None, manifest.plugin("@semantic-release/github"));
+}
+
+#[test]
+fn should_convert_string_plugin_when_configured() {
+    let mut manifest = manifest();
+
+    manifest
+        .plugin_mut("@semantic-release/commit-analyzer")
+        .unwrap()
+        .insert("preset".to_owned(), "conventionalcommits".into());
+
+    assert_eq!(
+        r#"
+{
+  "plugins": [
+    [
+      "@semantic-release/commit-analyzer",
+      {
+        "preset": "conventionalcommits"
+      }
+    ],
+    [
+      "@semantic-release/npm",
+      {
+        "npmPublish": false
+      }
+    ]
+  ]
+}
+"#
+        .trim(),
+        manifest.to_string()
+    );
+}
+
+#[test]
+fn should_insert_plugins_at_index() {
+    let mut manifest = manifest();
+
+    manifest
+        .insert_plugin(1, "@semantic-release/release-notes-generator", None)
+        .unwrap();
+    manifest
+        .insert_plugin(
+            usize::MAX,
+            "@semantic-release/git",
+            Some(object(r#"{"assets": ["CHANGELOG.md"]}"#)),
+        )
+        .unwrap();
+
+    assert_eq!(
+        r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer",
+    "@semantic-release/release-notes-generator",
+    [
+      "@semantic-release/npm",
+      {
+        "npmPublish": false
+      }
+    ],
+    [
+      "@semantic-release/git",
+      {
+        "assets": [
+          "CHANGELOG.md"
+        ]
+      }
+    ]
+  ]
+}
+"#
+        .trim(),
+        manifest.to_string()
+    );
+}
+
+#[test]
+fn should_refuse_to_insert_duplicate_plugin() {
+    let mut manifest = manifest();
+
+    let err = manifest
+        .insert_plugin(0, "@semantic-release/npm", None)
+        .unwrap_err();
+    assert!(err.to_string().contains("already configured"), "{}", err);
+}
+
+#[test]
+fn should_create_plugins_array_on_insert() {
+    let mut manifest = SemanticReleaseManifest::from_str("{}").unwrap();
+
+    manifest
+        .insert_plugin(0, "@semantic-release/github", None)
+        .unwrap();
+
+    assert_eq!(
+        r#"
+{
+  "plugins": [
+    "@semantic-release/github"
+  ]
+}
+"#
+        .trim(),
+        manifest.to_string()
+    );
+}
+
+#[test]
+fn should_remove_plugin() {
+    let mut manifest = manifest();
+
+    manifest.remove_plugin("@semantic-release/npm").unwrap();
+    let err = manifest.remove_plugin("@semantic-release/npm").unwrap_err();
+    assert!(err.to_string().contains("No plugin named"), "{}", err);
+    assert_eq!(
+        r#"
+{
+  "plugins": [
+    "@semantic-release/commit-analyzer"
+  ]
+}
+"#
+        .trim(),
+        manifest.to_string()
+    );
+}
@@ -0,0 +1,315 @@
+use std::collections::HashSet;
+use std::fs;
+use std::iter::FromIterator;
+
+use configure_semantic_release_assets::{OnDuplicateLabel, SemanticReleaseConfiguration, WriteTo};
+
+mod common;
+
+fn check(
+    (test_name, file_name, initial): (&str, &str, &str),
+    edit: impl FnOnce(&mut SemanticReleaseConfiguration),
+    expected: &str,
+) {
+    let path = common::file_in_temp_dir(test_name, file_name, initial);
+    let mut configuration = SemanticReleaseConfiguration::read_from_file(&path).unwrap();
+    edit(&mut configuration);
+    configuration.write_if_modified(WriteTo::InPlace).unwrap();
+
+    assert_eq!(expected, fs::read_to_string(&path).unwrap());
+}
+
+fn whitelist(labels: &str) -> impl FnOnce(&mut SemanticReleaseConfiguration) + '_ {
+    move |configuration| {
+        configuration.apply_whitelist(HashSet::from_iter(
+            labels.split_whitespace().map(|s| s.to_owned()),
+        ));
+    }
+}
+
+#[test]
+fn should_preserve_compact_arrays_and_missing_trailing_newline() {
+    check(
+        (
+            "preserve_compact",
+            ".releaserc.json",
+            r#"{
+  "branches": ["main", "next"],
+  "plugins": [
+    ["@semantic-release/github", {"assets": [
+      {"path": "dist/a", "label": "a"},
+      {"path": "dist/b", "label": "b"},
+      {"path": "dist/c", "label": "c"}
+    ]}]
+  ]
+}"#,
+        ),
+        whitelist("a c"),
+        r#"{
+  "branches": ["main", "next"],
+  "plugins": [
+    ["@semantic-release/github", {"assets": [
+      {"path": "dist/a", "label": "a"},
+      {"path": "dist/c", "label": "c"}
+    ]}]
+  ]
+}"#,
+    );
+}
+
+#[test]
+fn should_preserve_four_space_indentation() {
+    check(
+        (
+            "preserve_four_spaces",
+            ".releaserc.json",
+            r#"{
+    "plugins": [
+        [
+            "@semantic-release/github",
+            {
+                "assets": [
+                    {
+                        "path": "dist/a",
+                        "label": "a"
+                    }
+                ]
+            }
+        ]
+    ]
+}
+"#,
+        ),
+        |configuration| {
+            configuration
+                .add_asset("dist/b", "b", None, OnDuplicateLabel::Refuse)
+                .unwrap();
+        },
+        r#"{
+    "plugins": [
+        [
+            "@semantic-release/github",
+            {
+                "assets": [
+                    {
+                        "path": "dist/a",
+                        "label": "a"
+                    },
+                    {
+                        "path": "dist/b",
+                        "label": "b"
+                    }
+                ]
+            }
+        ]
+    ]
+}
+"#,
+    );
+}
+
+#[test]
+fn should_preserve_tab_indentation() {
+    check(
+        (
+            "preserve_tabs",
+            ".releaserc.json",
+            "{\n\t\"plugins\": [\n\t\t\"@semantic-release/github\"\n\t]\n}\n",
+        ),
+        |configuration| {
+            configuration
+                .add_asset("dist/a", "a", None, OnDuplicateLabel::Refuse)
+                .unwrap();
+        },
+        "{\n\t\"plugins\": [\n\t\t[\n\t\t\t\"@semantic-release/github\",\n\t\t\t{\n\t\t\t\t\"assets\": [\n\t\t\t\t\t{\n\t\t\t\t\t\t\"path\": \"dist/a\",\n\t\t\t\t\t\t\"label\": \"a\"\n\t\t\t\t\t}\n\t\t\t\t]\n\t\t\t}\n\t\t]\n\t]\n}\n",
+    );
+}
+
+#[test]
+fn should_preserve_untouched_regions_of_package_json() {
+    check(
+        (
+            "preserve_package_json",
+            "package.json",
+            r#"{
+  "name": "example",
+  "scripts": { "test": "true" },
+  "release": {
+    "plugins": [
+      ["@semantic-release/github", { "assets": [
+        { "path": "dist/a", "label": "a" },
+        { "path": "dist/b", "label": "b" }
+      ] }]
+    ]
+  }
+}
+"#,
+        ),
+        whitelist("b"),
+        r#"{
+  "name": "example",
+  "scripts": { "test": "true" },
+  "release": {
+    "plugins": [
+      ["@semantic-release/github", { "assets": [
+        { "path": "dist/b", "label": "b" }
+      ] }]
+    ]
+  }
+}
+"#,
+    );
+}
+
+#[test]
+fn should_keep_line_comments_with_their_assets_when_removing() {
+    check(
+        (
+            "preserve_comments_remove",
+            "release.config.js",
+            r#"module.exports = {
+  plugins: [
+    ['@semantic-release/github', {
+      assets: [
+        { path: 'dist/a', label: 'linux' }, // Linux build
+        { path: 'dist/b', label: 'macos' }, // macOS build
+        { path: 'dist/c', label: 'windows' } // Windows build
+      ]
+    }]
+  ]
+};
+"#,
+        ),
+        |configuration| {
+            configuration.apply_blacklist(HashSet::from_iter(["macos".to_owned()]));
+        },
+        r#"module.exports = {
+  plugins: [
+    ['@semantic-release/github', {
+      assets: [
+        { path: 'dist/a', label: 'linux' }, // Linux build
+        { path: 'dist/c', label: 'windows' } // Windows build
+      ]
+    }]
+  ]
+};
+"#,
+    );
+
+    check(
+        (
+            "preserve_comments_remove_last",
+            "release.config.js",
+            r#"module.exports = {
+  plugins: [
+    ['@semantic-release/github', {
+      assets: [
+        { path: 'dist/a', label: 'linux' }, // Linux build
+        { path: 'dist/b', label: 'macos' } // macOS build
+      ]
+    }]
+  ]
+};
+"#,
+        ),
+        whitelist("linux"),
+        r#"module.exports = {
+  plugins: [
+    ['@semantic-release/github', {
+      assets: [
+        { path: 'dist/a', label: 'linux' } // Linux build
+      ]
+    }]
+  ]
+};
+"#,
+    );
+}
+
+#[test]
+fn should_keep_line_comments_with_their_elements_when_inserting() {
+    check(
+        (
+            "preserve_comments_append",
+            "release.config.js",
+            r#"module.exports = {
+  plugins: [
+    ['@semantic-release/github', {
+      assets: [
+        { path: 'dist/a', label: 'linux' } // keep
+      ]
+    }]
+  ]
+};
+"#,
+        ),
+        |configuration| {
+            configuration
+                .add_asset("dist/b", "macos", None, OnDuplicateLabel::Refuse)
+                .unwrap();
+        },
+        r#"module.exports = {
+  plugins: [
+    ['@semantic-release/github', {
+      assets: [
+        { path: 'dist/a', label: 'linux' }, // keep
+        {
+          path: 'dist/b',
+          label: 'macos'
+        }
+      ]
+    }]
+  ]
+};
+"#,
+    );
+
+    check(
+        (
+            "preserve_comments_insert_first",
+            "release.config.js",
+            r#"module.exports = {
+  plugins: [
+    '@semantic-release/commit-analyzer', // analyze commits
+    '@semantic-release/github', // publish the release
+  ],
+};
+"#,
+        ),
+        |configuration| {
+            configuration
+                .insert_plugin(0, "@semantic-release/exec", None)
+                .unwrap();
+        },
+        r#"module.exports = {
+  plugins: [
+    '@semantic-release/exec',
+    '@semantic-release/commit-analyzer', // analyze commits
+    '@semantic-release/github', // publish the release
+  ],
+};
+"#,
+    );
+}
+
+#[test]
+fn should_separate_inserted_elements_of_compact_arrays_with_a_space() {
+    check(
+        (
+            "preserve_compact_insert",
+            ".releaserc.json",
+            r#"{"plugins": [["@semantic-release/github", {"assets": [{"path": "dist/a", "label": "a"}]}]]}
+"#,
+        ),
+        |configuration| {
+            configuration
+                .add_asset("dist/b", "b", None, OnDuplicateLabel::Refuse)
+                .unwrap();
+        },
+        r#"{"plugins": [["@semantic-release/github", {"assets": [{"path": "dist/a", "label": "a"}, {
+    "path": "dist/b",
+    "label": "b"
+  }]}]]}
+"#,
+    );
+}
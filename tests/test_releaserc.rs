@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+use std::fs;
+use std::iter::FromIterator;
+use std::path::PathBuf;
+
+use configure_semantic_release_assets::{ManifestFormat, SemanticReleaseConfiguration, WriteTo};
+
+mod common;
+
+fn releaserc_in_temp_dir(test_name: &str, contents: &str) -> PathBuf {
+    common::file_in_temp_dir(test_name, ".releaserc", contents)
+}
+
+#[test]
+fn should_read_and_write_extensionless_releaserc_as_json() {
+    let path = releaserc_in_temp_dir(
+        "releaserc_json",
+        r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#,
+    );
+
+    let mut configuration = SemanticReleaseConfiguration::read_from_file(&path).unwrap();
+    assert_eq!(ManifestFormat::Json, configuration.format());
+
+    configuration.apply_whitelist(HashSet::from_iter(["aarch64-apple-darwin".to_owned()]));
+    configuration.write_if_modified(WriteTo::InPlace).unwrap();
+
+    assert_eq!(
+        r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#,
+        fs::read_to_string(&path).unwrap()
+    );
+}
+
+#[test]
+fn should_read_and_write_extensionless_releaserc_as_yaml() {
+    let path = releaserc_in_temp_dir(
+        "releaserc_yaml",
+        r#"plugins:
+  - - "@semantic-release/github"
+    - assets:
+        - path: dist/x86_64-unknown-linux-musl
+          label: x86_64-unknown-linux-musl
+        - path: dist/aarch64-apple-darwin
+          label: aarch64-apple-darwin
+"#,
+    );
+
+    let mut configuration = SemanticReleaseConfiguration::read_from_file(&path).unwrap();
+    assert_eq!(ManifestFormat::Yaml, configuration.format());
+
+    configuration.apply_whitelist(HashSet::from_iter(["aarch64-apple-darwin".to_owned()]));
+    configuration.write_if_modified(WriteTo::InPlace).unwrap();
+
+    assert_eq!(
+        r#"plugins:
+- - '@semantic-release/github'
+  - assets:
+    - path: dist/aarch64-apple-darwin
+      label: aarch64-apple-darwin
+"#,
+        fs::read_to_string(&path).unwrap()
+    );
+}
+
+#[test]
+fn should_report_malformed_json_releaserc_as_json() {
+    for (test_name, contents) in [
+        (
+            "releaserc_json_trailing_comma",
+            "{\n  \"plugins\": [\"@semantic-release/github\"],\n}\n",
+        ),
+        ("releaserc_json_unquoted_key", "{ plugins: [] }\n"),
+    ] {
+        let path = releaserc_in_temp_dir(test_name, contents);
+
+        let err = match SemanticReleaseConfiguration::read_from_file(&path) {
+            Ok(_) => panic!("expected {:?} to be rejected", contents),
+            Err(err) => err,
+        };
+        assert!(!err.to_string().contains("YAML"), "{}", err);
+        assert!(std::error::Error::source(&err)
+            .unwrap()
+            .is::<serde_json::Error>());
+        assert_eq!(contents, fs::read_to_string(&path).unwrap());
+    }
+}
+
+#[test]
+fn should_warn_when_rewriting_yaml_in_place() {
+    let path = releaserc_in_temp_dir(
+        "releaserc_yaml_in_place",
+        r#"# Release configuration
+plugins:
+  - - "@semantic-release/github"
+    - assets:
+        - path: dist/x86_64-unknown-linux-musl
+          label: x86_64-unknown-linux-musl
+        - path: dist/aarch64-apple-darwin
+          label: aarch64-apple-darwin
+"#,
+    );
+
+    let output = common::binary()
+        .arg("--input")
+        .arg(&path)
+        .args(["--in-place", "whitelist", "aarch64-apple-darwin"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("drops its comments"));
+    assert!(!fs::read_to_string(&path).unwrap().contains("x86_64"));
+}
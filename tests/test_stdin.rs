@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+use configure_semantic_release_assets::{ManifestFormat, SemanticReleaseConfiguration, WriteTo};
+
+mod common;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+#[test]
+fn should_read_manifest_from_reader() {
+    let mut configuration = SemanticReleaseConfiguration::from_reader(
+        SEMANTIC_RELEASE_CONFIG.as_bytes(),
+        ManifestFormat::Json,
+    )
+    .unwrap();
+    configuration.apply_whitelist(HashSet::from_iter(["aarch64-apple-darwin".to_owned()]));
+
+    assert_eq!(ManifestFormat::Json, configuration.format());
+    assert_eq!(1, configuration.manifest().assets().len());
+}
+
+#[test]
+fn should_refuse_to_edit_stream_in_place() {
+    let mut configuration = SemanticReleaseConfiguration::from_reader(
+        SEMANTIC_RELEASE_CONFIG.as_bytes(),
+        ManifestFormat::Json,
+    )
+    .unwrap();
+    configuration.apply_whitelist(HashSet::from_iter(["aarch64-apple-darwin".to_owned()]));
+
+    let err = configuration
+        .write_if_modified(WriteTo::InPlace)
+        .unwrap_err();
+    assert!(err.to_string().contains("stdin"), "{}", err);
+}
+
+#[test]
+fn should_reject_in_place_for_stdin_before_reading_it() {
+    // Nothing would change, so only the arguments can be at fault
+    let output = common::run_with_stdin(
+        &[
+            "--input",
+            "-",
+            "--format",
+            "json",
+            "--in-place",
+            "whitelist",
+            "x86_64-unknown-linux-musl",
+            "aarch64-apple-darwin",
+        ],
+        SEMANTIC_RELEASE_CONFIG,
+    );
+
+    assert_eq!(Some(2), output.status.code());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("--in-place"));
+}
+
+#[test]
+fn should_name_stdin_in_check_summary() {
+    let output = common::run_with_stdin(
+        &[
+            "--input",
+            "-",
+            "--format",
+            "json",
+            "--check",
+            "whitelist",
+            "aarch64-apple-darwin",
+        ],
+        SEMANTIC_RELEASE_CONFIG,
+    );
+
+    assert_eq!(
+        "<stdin> would be modified\n- x86_64-unknown-linux-musl (dist/x86_64-unknown-linux-musl)\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn should_edit_manifest_piped_through_stdin() {
+    let output = common::run_with_stdin(
+        &[
+            "--input",
+            "-",
+            "--format",
+            "json",
+            "whitelist",
+            "aarch64-apple-darwin",
+        ],
+        SEMANTIC_RELEASE_CONFIG,
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/aarch64-apple-darwin",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#,
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn should_edit_yaml_piped_through_stdin() {
+    let output = common::run_with_stdin(
+        &["--input", "-", "--format", "yaml", "blacklist", "a"],
+        r#"plugins:
+- - "@semantic-release/github"
+  - assets:
+    - path: dist/a
+      label: a
+    - path: dist/b
+      label: b
+"#,
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        r#"plugins:
+- - '@semantic-release/github'
+  - assets:
+    - path: dist/b
+      label: b
+"#,
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn should_require_format_when_reading_stdin() {
+    let output = common::run_with_stdin(
+        &["--input", "-", "whitelist", "aarch64-apple-darwin"],
+        SEMANTIC_RELEASE_CONFIG,
+    );
+
+    assert_eq!(Some(2), output.status.code());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("--format"));
+}
+
+#[test]
+fn should_reject_format_unless_reading_stdin() {
+    let output = common::run_with_stdin(
+        &[
+            "--input",
+            "release.config.json",
+            "--format",
+            "yaml",
+            "whitelist",
+            "aarch64-apple-darwin",
+        ],
+        "",
+    );
+
+    assert_eq!(Some(2), output.status.code());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("--format applies only"));
+}
+
+#[test]
+fn should_edit_file_named_like_stdin_in_place() {
+    let path = common::file_in_temp_dir("stdin_file_name", "<stdin>", SEMANTIC_RELEASE_CONFIG);
+
+    let mut configuration = SemanticReleaseConfiguration::read_from_file(&path).unwrap();
+    configuration.apply_whitelist(HashSet::from_iter(["aarch64-apple-darwin".to_owned()]));
+    configuration.write_if_modified(WriteTo::InPlace).unwrap();
+
+    assert!(!std::fs::read_to_string(&path)
+        .unwrap()
+        .contains("x86_64-unknown-linux-musl"));
+}
@@ -0,0 +1,206 @@
+use std::process::Output;
+
+mod common;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"{
+  "plugins": [
+    [
+      "@semantic-release/github",
+      {
+        "assets": [
+          {
+            "path": "dist/x86_64-unknown-linux-musl/tool",
+            "label": "x86_64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/aarch64-unknown-linux-musl/tool",
+            "label": "aarch64-unknown-linux-musl"
+          },
+          {
+            "path": "dist/x86_64-apple-darwin/tool",
+            "label": "x86_64-apple-darwin"
+          },
+          {
+            "path": "dist/aarch64-apple-darwin/tool",
+            "label": "aarch64-apple-darwin"
+          }
+        ]
+      }
+    ]
+  ]
+}
+"#;
+
+fn whitelist(args: &[&str]) -> Output {
+    let args = [&["--input", "-", "--format", "json", "whitelist"], args].concat();
+    common::run_with_stdin(&args, SEMANTIC_RELEASE_CONFIG)
+}
+
+fn whitelisted_labels(args: &[&str]) -> Vec<String> {
+    let output = whitelist(args);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("\"label\": "))
+        .map(|label| label.trim_matches('"').to_owned())
+        .collect()
+}
+
+#[test]
+fn should_whitelist_from_newline_separated_file() {
+    let path = common::file_in_temp_dir(
+        "whitelist_from_lines",
+        "targets.txt",
+        "# release targets\nx86_64-unknown-linux-musl\n\naarch64-apple-darwin\n",
+    );
+
+    assert_eq!(
+        vec!["x86_64-unknown-linux-musl", "aarch64-apple-darwin"],
+        whitelisted_labels(&["--from-file", path.to_str().unwrap()])
+    );
+}
+
+#[test]
+fn should_whitelist_from_json_array() {
+    let path = common::file_in_temp_dir(
+        "whitelist_from_json",
+        "targets.json",
+        r#"["x86_64-apple-darwin", "aarch64-apple-darwin"]"#,
+    );
+
+    assert_eq!(
+        vec!["x86_64-apple-darwin", "aarch64-apple-darwin"],
+        whitelisted_labels(&["--from-file", path.to_str().unwrap()])
+    );
+}
+
+#[test]
+fn should_whitelist_from_workflow_matrix() {
+    let path = common::file_in_temp_dir(
+        "whitelist_from_workflow",
+        "release.yml",
+        r#"
+jobs:
+  build:
+    strategy:
+      matrix:
+        include:
+          - target: x86_64-unknown-linux-musl
+            os: ubuntu-latest
+          - target: aarch64-apple-darwin
+            os: macos-latest
+"#,
+    );
+
+    assert_eq!(
+        vec!["x86_64-unknown-linux-musl", "aarch64-apple-darwin"],
+        whitelisted_labels(&[
+            "--from-file",
+            path.to_str().unwrap(),
+            "--key",
+            "jobs.build.strategy.matrix.include.target",
+        ])
+    );
+}
+
+#[test]
+fn should_skip_matrix_entries_without_the_key() {
+    let path = common::file_in_temp_dir(
+        "whitelist_from_mixed_matrix",
+        "release.yml",
+        r#"
+jobs:
+  build:
+    strategy:
+      matrix:
+        target:
+          - x86_64-unknown-linux-musl
+        include:
+          - target: aarch64-apple-darwin
+            os: macos-latest
+          - os: windows-latest
+            experimental: true
+          - target: x86_64-apple-darwin
+            os: macos-latest
+"#,
+    );
+
+    assert_eq!(
+        vec!["x86_64-apple-darwin", "aarch64-apple-darwin"],
+        whitelisted_labels(&[
+            "--from-file",
+            path.to_str().unwrap(),
+            "--key",
+            "jobs.build.strategy.matrix.include.target",
+        ])
+    );
+}
+
+#[test]
+fn should_refuse_matrix_where_no_entry_has_the_key() {
+    let path = common::file_in_temp_dir(
+        "whitelist_from_matrix_without_key",
+        "release.yml",
+        r#"
+jobs:
+  build:
+    strategy:
+      matrix:
+        include:
+          - os: ubuntu-latest
+          - os: windows-latest
+"#,
+    );
+
+    let output = whitelist(&[
+        "--from-file",
+        path.to_str().unwrap(),
+        "--key",
+        "jobs.build.strategy.matrix.include.target",
+    ]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("jobs.build.strategy.matrix.include.target"));
+}
+
+#[test]
+fn should_combine_file_and_command_line_entries() {
+    let path = common::file_in_temp_dir(
+        "whitelist_from_file_and_args",
+        "targets.txt",
+        "x86_64-unknown-linux-musl\n",
+    );
+
+    assert_eq!(
+        vec!["x86_64-unknown-linux-musl", "x86_64-apple-darwin"],
+        whitelisted_labels(&["--from-file", path.to_str().unwrap(), "x86_64-apple-darwin"])
+    );
+}
+
+#[test]
+fn should_refuse_missing_key_path() {
+    let path = common::file_in_temp_dir(
+        "whitelist_from_missing_key",
+        "release.yml",
+        "jobs:\n  build:\n    runs-on: ubuntu-latest\n",
+    );
+
+    let output = whitelist(&[
+        "--from-file",
+        path.to_str().unwrap(),
+        "--key",
+        "jobs.build.strategy.matrix.target",
+    ]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("jobs.build.strategy.matrix.target"));
+}
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+use configure_semantic_release_assets::SemanticReleaseManifest;
+
+const SEMANTIC_RELEASE_CONFIG: &str = r#"
+plugins:
+  - "@semantic-release/commit-analyzer"
+  - "@semantic-release/release-notes-generator"
+  - - "@semantic-release/github"
+    - assets:
+        - path: dist/x86_64-unknown-linux-musl/BINARY_NAME-x86_64-unknown-linux-musl
+          label: x86_64-unknown-linux-musl
+        - path: dist/aarch64-apple-darwin/BINARY_NAME-aarch64-apple-darwin
+          label: aarch64-apple-darwin
+        - path: dist/SHA256SUMS.txt
+          label: SHA256SUMS.txt
+  - - "@semantic-release/git"
+    - assets:
+        - CHANGELOG.md
+        - Cargo.toml
+        - Cargo.lock
+"#;
+
+fn check(initial: &str, whitelist: &str, expected: &str) {
+    let mut manifest = SemanticReleaseManifest::from_yaml_str(initial).unwrap();
+    manifest.apply_whitelist(HashSet::from_iter(
+        whitelist.split_whitespace().map(|s| s.to_owned()),
+    ));
+    assert_eq!(
+        expected.trim_start(),
+        manifest.to_yaml_string().unwrap().as_str()
+    )
+}
+
+#[test]
+fn should_round_trip_yaml_when_whitelist_matches_contents() {
+    check(
+        SEMANTIC_RELEASE_CONFIG,
+        "x86_64-unknown-linux-musl aarch64-apple-darwin SHA256SUMS.txt",
+        r#"
+plugins:
+- '@semantic-release/commit-analyzer'
+- '@semantic-release/release-notes-generator'
+- - '@semantic-release/github'
+  - assets:
+    - path: dist/x86_64-unknown-linux-musl/BINARY_NAME-x86_64-unknown-linux-musl
+      label: x86_64-unknown-linux-musl
+    - path: dist/aarch64-apple-darwin/BINARY_NAME-aarch64-apple-darwin
+      label: aarch64-apple-darwin
+    - path: dist/SHA256SUMS.txt
+      label: SHA256SUMS.txt
+- - '@semantic-release/git'
+  - assets:
+    - CHANGELOG.md
+    - Cargo.toml
+    - Cargo.lock
+"#,
+    )
+}
+
+#[test]
+fn should_whitelist_yaml_assets() {
+    check(
+        SEMANTIC_RELEASE_CONFIG,
+        "aarch64-apple-darwin",
+        r#"
+plugins:
+- '@semantic-release/commit-analyzer'
+- '@semantic-release/release-notes-generator'
+- - '@semantic-release/github'
+  - assets:
+    - path: dist/aarch64-apple-darwin/BINARY_NAME-aarch64-apple-darwin
+      label: aarch64-apple-darwin
+- - '@semantic-release/git'
+  - assets:
+    - CHANGELOG.md
+    - Cargo.toml
+    - Cargo.lock
+"#,
+    )
+}